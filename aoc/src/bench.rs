@@ -0,0 +1,238 @@
+use std::{
+    fs,
+    hint::black_box,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    days::{self, YEAR},
+    input::Input,
+    store::{Store, DEFAULT_PROFILE},
+};
+
+const DEFAULT_BASELINE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/bench.json");
+
+// Phases faster than this are mostly noise, so never count them as regressions.
+const NOISE_FLOOR: Duration = Duration::from_micros(100);
+
+#[derive(Args)]
+pub struct BenchArgs {
+    /// Day to benchmark. Benchmarks every day if omitted.
+    #[arg(long)]
+    day: Option<u32>,
+
+    /// Times to run each phase. The median is reported.
+    #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u32).range(1..))]
+    runs: u32,
+
+    /// Baseline to compare against.
+    #[arg(long, default_value = DEFAULT_BASELINE)]
+    baseline: PathBuf,
+
+    /// Save the results as the new baseline.
+    #[arg(long)]
+    save: bool,
+
+    /// Flag phases more than this many percent slower than the baseline.
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+}
+
+/// Median time of each phase of one day, in nanoseconds.
+#[derive(Serialize, Deserialize, Clone, Copy)]
+struct Timings {
+    day: u32,
+    parse_ns: u64,
+    part1_ns: u64,
+    part2_ns: u64,
+}
+
+impl Timings {
+    fn phases(&self) -> [u64; 3] {
+        [self.parse_ns, self.part1_ns, self.part2_ns]
+    }
+
+    fn total(&self) -> u64 {
+        self.phases().iter().sum()
+    }
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct Baseline {
+    days: Vec<Timings>,
+}
+
+impl Baseline {
+    fn load(path: &Path) -> Result<Option<Self>, String> {
+        let json = match fs::read_to_string(path) {
+            Ok(json) => json,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(format!("{}: {e}", path.display())),
+        };
+
+        serde_json::from_str(&json)
+            .map(Some)
+            .map_err(|e| format!("{}: {e}", path.display()))
+    }
+
+    fn get(&self, day: u32) -> Option<&Timings> {
+        self.days.iter().find(|t| t.day == day)
+    }
+
+    // Replace the timings for each day in `results`, keeping the rest.
+    fn update(&mut self, results: &[Timings]) {
+        self.days.retain(|t| results.iter().all(|r| r.day != t.day));
+        self.days.extend_from_slice(results);
+        self.days.sort_by_key(|t| t.day);
+    }
+}
+
+// Median wall time of `runs` calls to `f`.
+fn median<T>(runs: u32, mut f: impl FnMut() -> T) -> u64 {
+    let mut times: Vec<u64> = (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed().as_nanos() as u64
+        })
+        .collect();
+
+    times.sort_unstable();
+    times[times.len() / 2]
+}
+
+fn bench_day(day: &days::Day, runs: u32, store: &Store) -> Option<Timings> {
+    let path = match store.resolve(YEAR, day, DEFAULT_PROFILE) {
+        Ok(path) => path,
+        Err(e) => {
+            eprintln!("{e}");
+            return None;
+        }
+    };
+
+    let input = match Input::load(&path) {
+        Ok(s) => s,
+        Err(e) => {
+            eprintln!("Day {}: {}: {e}", day.day, path.display());
+            return None;
+        }
+    };
+
+    let model = match day.solution.parse(&input) {
+        Ok(model) => model,
+        Err(e) => {
+            eprint!("{}", e.in_file(day.day, &path).render(&input));
+            return None;
+        }
+    };
+
+    Some(Timings {
+        day: day.day,
+        parse_ns: median(runs, || day.solution.parse(&input)),
+        part1_ns: median(runs, || day.solution.part1(model.as_ref())),
+        part2_ns: median(runs, || day.solution.part2(model.as_ref())),
+    })
+}
+
+// A timing, with its change from the baseline if we have one. Returns whether it regressed.
+fn cell(ns: u64, base: Option<u64>, threshold: f64) -> (String, bool) {
+    let time = format!("{:.2?}", Duration::from_nanos(ns));
+
+    let Some(base) = base.filter(|b| *b > 0) else {
+        return (time, false);
+    };
+
+    let change = (ns as f64 / base as f64 - 1.0) * 100.0;
+    let regressed = change > threshold && Duration::from_nanos(ns.abs_diff(base)) > NOISE_FLOOR;
+
+    let marker = if regressed { " !" } else { "" };
+    (format!("{time} ({change:+.0}%){marker}"), regressed)
+}
+
+pub fn bench(args: BenchArgs, store: &Store) -> ExitCode {
+    let selected: Vec<&days::Day> = match args.day {
+        Some(day) => match days::get(day) {
+            Some(d) => vec![d],
+            None => {
+                eprintln!("No solution for day {day}");
+                return ExitCode::FAILURE;
+            }
+        },
+        None => days::solved(store, DEFAULT_PROFILE),
+    };
+
+    let baseline = match Baseline::load(&args.baseline) {
+        Ok(b) => b,
+        Err(e) => {
+            eprintln!("Couldn't load baseline: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut ok = true;
+    let mut any_regressed = false;
+    let mut results: Vec<Timings> = Vec::new();
+
+    println!(
+        "{:>3}  {:>22}  {:>22}  {:>22}  {:>22}",
+        "Day", "Parse", "Part 1", "Part 2", "Total"
+    );
+
+    for day in selected {
+        let Some(timings) = bench_day(day, args.runs, store) else {
+            ok = false;
+            continue;
+        };
+
+        let base = baseline.as_ref().and_then(|b| b.get(day.day));
+
+        let mut cells = Vec::new();
+        for (idx, ns) in timings.phases().into_iter().enumerate() {
+            let (c, regressed) = cell(ns, base.map(|b| b.phases()[idx]), args.threshold);
+            any_regressed |= regressed;
+            cells.push(c);
+        }
+        let (total, _) = cell(timings.total(), base.map(|b| b.total()), args.threshold);
+
+        println!(
+            "{:>3}  {:>22}  {:>22}  {:>22}  {:>22}",
+            day.day, cells[0], cells[1], cells[2], total
+        );
+
+        results.push(timings);
+    }
+
+    if any_regressed {
+        println!(
+            "\n! marks phases more than {}% slower than {}",
+            args.threshold,
+            args.baseline.display()
+        );
+    } else if baseline.is_none() {
+        println!("\nNo baseline at {}", args.baseline.display());
+    }
+
+    if args.save {
+        let mut baseline = baseline.unwrap_or_default();
+        baseline.update(&results);
+
+        let json = serde_json::to_string_pretty(&baseline).unwrap();
+        if let Err(e) = fs::write(&args.baseline, json + "\n") {
+            eprintln!("Couldn't save baseline: {}: {e}", args.baseline.display());
+            return ExitCode::FAILURE;
+        }
+
+        println!("Saved baseline to {}", args.baseline.display());
+    }
+
+    if ok && !any_regressed {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
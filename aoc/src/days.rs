@@ -0,0 +1,184 @@
+use common::{Configure, DynScan, DynSolution, DynStream};
+
+use crate::store::Store;
+
+/// The year these solutions are for.
+pub const YEAR: u32 = 2023;
+
+pub struct Day {
+    pub day: u32,
+    // Input bundled with the day's crate, used when the store hasn't cached one.
+    pub input: &'static str,
+    pub solution: &'static dyn DynSolution,
+}
+
+pub const DAYS: &[Day] = &[
+    Day {
+        day: 1,
+        input: d01::INPUT,
+        solution: &d01::Day01::PUZZLE,
+    },
+    Day {
+        day: 2,
+        input: d02::INPUT,
+        solution: &d02::Day02::PUZZLE,
+    },
+    Day {
+        day: 3,
+        input: d03::INPUT,
+        solution: &d03::Day03,
+    },
+    Day {
+        day: 4,
+        input: d04::INPUT,
+        solution: &d04::Day04,
+    },
+    Day {
+        day: 5,
+        input: d05::INPUT,
+        solution: &d05::Day05,
+    },
+    Day {
+        day: 6,
+        input: d06::INPUT,
+        solution: &d06::Day06,
+    },
+    Day {
+        day: 7,
+        input: d07::INPUT,
+        solution: &d07::Day07,
+    },
+    Day {
+        day: 8,
+        input: d08::INPUT,
+        solution: &d08::Day08,
+    },
+    Day {
+        day: 9,
+        input: d09::INPUT,
+        solution: &d09::Day09,
+    },
+    Day {
+        day: 10,
+        input: d10::INPUT,
+        solution: &d10::Day10,
+    },
+    Day {
+        day: 11,
+        input: d11::INPUT,
+        solution: &d11::Day11,
+    },
+    Day {
+        day: 12,
+        input: d12::INPUT,
+        solution: &d12::Day12,
+    },
+    Day {
+        day: 13,
+        input: d13::INPUT,
+        solution: &d13::Day13,
+    },
+    Day {
+        day: 14,
+        input: d14::INPUT,
+        solution: &d14::Day14,
+    },
+    Day {
+        day: 15,
+        input: d15::INPUT,
+        solution: &d15::Day15,
+    },
+    Day {
+        day: 16,
+        input: d16::INPUT,
+        solution: &d16::Day16,
+    },
+    Day {
+        day: 17,
+        input: d17::INPUT,
+        solution: &d17::Day17,
+    },
+    Day {
+        day: 18,
+        input: d18::INPUT,
+        solution: &d18::Day18,
+    },
+    Day {
+        day: 19,
+        input: d19::INPUT,
+        solution: &d19::Day19,
+    },
+    Day {
+        day: 20,
+        input: d20::INPUT,
+        solution: &d20::Day20,
+    },
+    Day {
+        day: 21,
+        input: d21::INPUT,
+        solution: &d21::Day21,
+    },
+    Day {
+        day: 22,
+        input: d22::INPUT,
+        solution: &d22::Day22,
+    },
+    Day {
+        day: 23,
+        input: d23::INPUT,
+        solution: &d23::Day23,
+    },
+    Day {
+        day: 24,
+        input: d24::INPUT,
+        solution: &d24::Day24,
+    },
+    Day {
+        day: 25,
+        input: d25::INPUT,
+        solution: &d25::Day25,
+    },
+];
+
+// Days whose answers can be worked out a line at a time, which is how the runner runs them.
+const STREAMS: &[(u32, &dyn DynStream)] = &[
+    (1, &d01::Day01::PUZZLE),
+    (2, &d02::Day02::PUZZLE),
+    (4, &d04::Day04),
+    (9, &d09::Day09),
+];
+
+// Days that can work out their answers from the whole input without parsing it, which the runner
+// does when they say to.
+const SCANS: &[(u32, &dyn DynScan)] = &[(1, &d01::Day01::PUZZLE)];
+
+// Days that take options.
+const CONFIGURE: &[(u32, &dyn Configure)] = &[(1, &d01::Day01::PUZZLE), (2, &d02::Day02::PUZZLE)];
+
+/// Every day with a part solved and an input to run it on for `profile`.
+pub fn solved(store: &Store, profile: &str) -> Vec<&'static Day> {
+    DAYS.iter()
+        .filter(|d| d.solution.solved(1) || d.solution.solved(2))
+        .filter(|d| store.has_input(YEAR, d, profile))
+        .collect()
+}
+
+pub fn get(day: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+pub fn stream(day: u32) -> Option<&'static dyn DynStream> {
+    STREAMS.iter().find(|(d, _)| *d == day).map(|(_, s)| *s)
+}
+
+/// The scan for `day`, if it has one and it's turned on.
+pub fn scan(day: u32) -> Option<&'static dyn DynScan> {
+    SCANS
+        .iter()
+        .find(|(d, s)| *d == day && s.scanning())
+        .map(|(_, s)| *s)
+}
+
+pub fn configure(day: u32) -> Option<&'static dyn Configure> {
+    CONFIGURE.iter().find(|(d, _)| *d == day).map(|(_, c)| *c)
+}
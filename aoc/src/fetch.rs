@@ -0,0 +1,140 @@
+use std::{fs, path::Path, process::ExitCode};
+
+use clap::Args;
+use tracing::info;
+
+use crate::{
+    days::YEAR,
+    store::{Store, DEFAULT_PROFILE},
+};
+
+#[derive(Args)]
+pub struct FetchArgs {
+    /// Day to fetch (1-25).
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: u32,
+
+    /// Year to fetch.
+    #[arg(long, default_value_t = YEAR)]
+    year: u32,
+
+    /// Profile to store the input under, for keeping other people's inputs.
+    #[arg(long, default_value = DEFAULT_PROFILE)]
+    profile: String,
+
+    /// Session cookie to log in to the puzzle site with.
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: Option<String>,
+
+    /// Site to fetch from.
+    #[arg(long, env = "AOC_BASE_URL", default_value = "https://adventofcode.com")]
+    base_url: String,
+}
+
+// Download `url` to `path`, unless it's already there. Returns whether it downloaded anything.
+fn download(url: &str, session: Option<&str>, path: &Path) -> Result<bool, String> {
+    if path.exists() {
+        return Ok(false);
+    }
+
+    let session = session.ok_or("Need a session cookie to fetch inputs, pass --session")?;
+
+    info!("fetching {url}");
+    let input = ureq::get(url)
+        .set("Cookie", &format!("session={session}"))
+        .call()
+        .map_err(|e| e.to_string())?
+        .into_string()
+        .map_err(|e| format!("{url}: {e}"))?;
+
+    let dir = path.parent().unwrap();
+    fs::create_dir_all(dir).map_err(|e| format!("{}: {e}", dir.display()))?;
+
+    // Write somewhere else first, so a failed write can't leave a truncated input in the cache
+    // that would then never be fetched again.
+    let partial = path.with_extension("part");
+    fs::write(&partial, input).map_err(|e| format!("{}: {e}", partial.display()))?;
+    fs::rename(&partial, path).map_err(|e| format!("{}: {e}", path.display()))?;
+
+    Ok(true)
+}
+
+pub fn fetch(args: FetchArgs, store: &Store) -> ExitCode {
+    let path = store.path(args.year, args.day, &args.profile);
+    let url = format!(
+        "{}/{}/day/{}/input",
+        args.base_url.trim_end_matches('/'),
+        args.year,
+        args.day
+    );
+
+    match download(&url, args.session.as_deref(), &path) {
+        Ok(true) => {
+            println!("Saved {}", path.display());
+            ExitCode::SUCCESS
+        }
+        Ok(false) => {
+            println!("Already have {}", path.display());
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env, fs,
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        process, thread,
+    };
+
+    use super::download;
+
+    #[test]
+    fn downloads_once() {
+        // A stub site that serves a single request, and remembers the cookie it was sent.
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/2023/day/1/input", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+
+            let mut cookie = None;
+            for line in BufReader::new(&stream).lines() {
+                let line = line.unwrap();
+                if line.is_empty() {
+                    break;
+                }
+                if let Some(c) = line.strip_prefix("Cookie: ") {
+                    cookie = Some(c.to_string());
+                }
+            }
+
+            let body = "1abc2\n";
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+
+            cookie
+        });
+
+        let dir = env::temp_dir().join(format!("aoc-fetch-test-{}", process::id()));
+        let path = dir.join("2023/01/default.txt");
+
+        assert_eq!(download(&url, Some("secret"), &path), Ok(true));
+        assert_eq!(server.join().unwrap().as_deref(), Some("session=secret"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1abc2\n");
+
+        // Nothing is listening any more, so this would fail if it tried to download again.
+        assert_eq!(download(&url, Some("secret"), &path), Ok(false));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,117 @@
+use std::{
+    fs::File,
+    io::{self, BufReader, ErrorKind, Read},
+    ops::Deref,
+    path::Path,
+    str,
+};
+
+use memmap2::Mmap;
+use sha2::{Digest, Sha256};
+
+/// The input path meaning standard input.
+pub const STDIN: &str = "-";
+
+// Files bigger than this are mapped rather than read, so a generated input of several gigabytes
+// isn't copied into memory before it's parsed.
+const MAP_OVER: u64 = 64 * 1024 * 1024;
+
+fn is_stdin(path: &Path) -> bool {
+    path.as_os_str() == STDIN
+}
+
+/// A whole input, for days that parse it all at once.
+pub enum Input {
+    Read(String),
+    Mapped(Utf8Map),
+}
+
+/// A mapped file that was valid UTF-8 when it was mapped, so it needn't be checked again on every
+/// look at it.
+pub struct Utf8Map(Mmap);
+
+/// How many bytes of `path` loading it maps rather than reads, which count against a process's
+/// address space but not the memory it allocates.
+pub fn mapped_len(path: &Path) -> u64 {
+    if is_stdin(path) {
+        return 0;
+    }
+
+    match path.metadata() {
+        Ok(metadata) if metadata.len() > MAP_OVER => metadata.len(),
+        _ => 0,
+    }
+}
+
+impl Input {
+    pub fn load(path: &Path) -> io::Result<Self> {
+        if is_stdin(path) {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            return Ok(Self::Read(input));
+        }
+
+        let mut file = File::open(path)?;
+        if file.metadata()?.len() <= MAP_OVER {
+            let mut input = String::new();
+            file.read_to_string(&mut input)?;
+            return Ok(Self::Read(input));
+        }
+
+        // SAFETY: the mapping is only unsound if the file changes under us, and inputs are only
+        // written before they're run.
+        let map = unsafe { Mmap::map(&file)? };
+        str::from_utf8(&map).map_err(|e| io::Error::new(ErrorKind::InvalidData, e))?;
+
+        Ok(Self::Mapped(Utf8Map(map)))
+    }
+}
+
+impl Deref for Input {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        match self {
+            Self::Read(input) => input,
+            // SAFETY: a `Utf8Map` is only made in `load`, after checking the whole mapping is UTF-8,
+            // and the file doesn't change under us for the same reason the mapping is sound.
+            Self::Mapped(Utf8Map(map)) => unsafe { str::from_utf8_unchecked(map) },
+        }
+    }
+}
+
+/// Reads an input, hashing it on the way through.
+pub struct Hashed<R> {
+    inner: R,
+    sha256: Sha256,
+}
+
+impl<R: Read> Hashed<R> {
+    pub fn sha256(self) -> String {
+        format!("{:x}", self.sha256.finalize())
+    }
+}
+
+impl<R: Read> Read for Hashed<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.sha256.update(&buf[..n]);
+        Ok(n)
+    }
+}
+
+/// An input read a line at a time, for days that stream it.
+pub type Lines = BufReader<Hashed<Box<dyn Read>>>;
+
+pub fn open(path: &Path) -> io::Result<Lines> {
+    let inner: Box<dyn Read> = if is_stdin(path) {
+        Box::new(io::stdin())
+    } else {
+        Box::new(File::open(path)?)
+    };
+
+    Ok(BufReader::new(Hashed {
+        inner,
+        sha256: Sha256::new(),
+    }))
+}
@@ -0,0 +1,213 @@
+use std::{fmt::Display, fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+pub const LEDGER: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/answers.toml");
+
+const HEADER: &str = "\
+# Accepted answers for each day's real input, checked by `aoc run` whenever it runs that input:
+# the one whose SHA-256 is `input_sha256`.
+#
+# Once the site accepts a day's answers, record them with `aoc run --day N --accept`. The parts
+# listed in a day's `unverified` are our own answers, not yet accepted by the site: runs are checked
+# against them, but a match isn't shown as correct.
+
+";
+
+/// How an answer compares to the one in the ledger.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Status {
+    Correct,
+    /// Same as the ledger's answer, but that hasn't been accepted by the site.
+    Unverified,
+    Regression {
+        expected: String,
+    },
+    Unknown,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Correct => write!(f, "✅"),
+            Status::Unverified => write!(f, "☑️ (unverified)"),
+            Status::Regression { expected } => write!(f, "❌ (expected {expected})"),
+            Status::Unknown => write!(f, "❓"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct Entry {
+    day: u32,
+    /// The input the answers are for. Entries without one are for whatever input is run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    input_sha256: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<String>,
+    /// Parts whose answers haven't been accepted by the site.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    unverified: Vec<u8>,
+}
+
+impl Entry {
+    fn part_mut(&mut self, part: u8) -> &mut Option<String> {
+        match part {
+            1 => &mut self.part1,
+            2 => &mut self.part2,
+            _ => unreachable!("Invalid part {part}"),
+        }
+    }
+}
+
+/// Accepted answers per day and part.
+#[derive(Serialize, Deserialize, Default)]
+pub struct Ledger {
+    #[serde(default)]
+    answer: Vec<Entry>,
+}
+
+impl Ledger {
+    /// Load the ledger at `path`. A missing file is an empty ledger.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let ledger = match fs::read_to_string(path) {
+            Ok(s) => s,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(format!("{}: {e}", path.display())),
+        };
+
+        toml::from_str(&ledger).map_err(|e| format!("{}: {e}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let ledger = toml::to_string(self).map_err(|e| e.to_string())?;
+        fs::write(path, format!("{HEADER}{ledger}")).map_err(|e| format!("{}: {e}", path.display()))
+    }
+
+    fn entry(&self, day: u32) -> Option<&Entry> {
+        self.answer.iter().find(|e| e.day == day)
+    }
+
+    /// Whether the ledger's answers for `day`, if any, are for the input with hash `input_sha256`.
+    /// Answers for anyone else's input say nothing about ours.
+    pub fn is_for(&self, day: u32, input_sha256: &str) -> bool {
+        self.entry(day)
+            .and_then(|e| e.input_sha256.as_deref())
+            .is_none_or(|sha| sha == input_sha256)
+    }
+
+    // The recorded answer, and whether the site accepted it.
+    fn expected(&self, day: u32, part: u8) -> Option<(&str, bool)> {
+        let entry = self.entry(day)?;
+        let expected = match part {
+            1 => entry.part1.as_deref(),
+            2 => entry.part2.as_deref(),
+            _ => None,
+        }?;
+
+        Some((expected, !entry.unverified.contains(&part)))
+    }
+
+    pub fn check(&self, day: u32, part: u8, answer: &str) -> Status {
+        match self.expected(day, part) {
+            Some((expected, true)) if expected == answer => Status::Correct,
+            Some((expected, false)) if expected == answer => Status::Unverified,
+            Some((expected, _)) => Status::Regression {
+                expected: expected.to_string(),
+            },
+            None => Status::Unknown,
+        }
+    }
+
+    /// Record `answer` as the accepted answer for the input with hash `input_sha256`, replacing any
+    /// previous one, and as verified. Answers for another input are dropped.
+    pub fn accept(&mut self, day: u32, part: u8, answer: &str, input_sha256: &str) {
+        let idx = match self.answer.iter().position(|e| e.day == day) {
+            Some(idx) => idx,
+            None => {
+                self.answer.push(Entry {
+                    day,
+                    input_sha256: None,
+                    part1: None,
+                    part2: None,
+                    unverified: Vec::new(),
+                });
+                self.answer.sort_by_key(|e| e.day);
+                self.answer.iter().position(|e| e.day == day).unwrap()
+            }
+        };
+
+        let entry = &mut self.answer[idx];
+        if entry.input_sha256.as_deref() != Some(input_sha256) {
+            *entry = Entry {
+                day,
+                input_sha256: Some(input_sha256.to_string()),
+                part1: None,
+                part2: None,
+                unverified: Vec::new(),
+            };
+        }
+        *entry.part_mut(part) = Some(answer.to_string());
+        entry.unverified.retain(|p| *p != part);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{Ledger, Status, LEDGER};
+    use crate::days;
+
+    #[test]
+    fn ledger_loads() {
+        let ledger = Ledger::load(Path::new(LEDGER)).unwrap();
+
+        for entry in &ledger.answer {
+            assert!(
+                days::get(entry.day).is_some(),
+                "Ledger has answers for day {}, which has no solution",
+                entry.day
+            );
+        }
+    }
+
+    #[test]
+    fn unverified_answers() {
+        let mut ledger: Ledger = toml::from_str(
+            "[[answer]]\nday = 1\npart1 = \"10\"\npart2 = \"20\"\nunverified = [2]\n",
+        )
+        .unwrap();
+
+        assert_eq!(ledger.check(1, 1, "10"), Status::Correct);
+        assert_eq!(ledger.check(1, 2, "20"), Status::Unverified);
+        assert_eq!(
+            ledger.check(1, 2, "21"),
+            Status::Regression {
+                expected: "20".to_string()
+            }
+        );
+
+        ledger.accept(1, 2, "20", "abc");
+        assert_eq!(ledger.check(1, 2, "20"), Status::Correct);
+    }
+
+    #[test]
+    fn answers_for_another_input() {
+        let mut ledger = Ledger::default();
+        ledger.accept(1, 1, "10", "abc");
+        ledger.accept(1, 2, "20", "abc");
+
+        assert!(ledger.is_for(1, "abc"));
+        assert!(!ledger.is_for(1, "def"));
+        assert!(ledger.is_for(2, "def"));
+
+        // Accepting an answer for a new input drops the old input's answers.
+        ledger.accept(1, 1, "11", "def");
+        assert!(ledger.is_for(1, "def"));
+        assert_eq!(ledger.check(1, 1, "11"), Status::Correct);
+        assert_eq!(ledger.check(1, 2, "20"), Status::Unknown);
+    }
+}
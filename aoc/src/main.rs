@@ -0,0 +1,614 @@
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process::{self, ExitCode},
+    thread,
+    time::{Duration, Instant},
+};
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use common::{Configured, DynScan, DynStream, Options, StreamError};
+use days::YEAR;
+use grid::render::{self, ImageFormat};
+use input::Input;
+use ledger::{Ledger, Status, LEDGER};
+use options::{OptionsFile, OPTIONS};
+use output::{DayRun, Format, PartRun};
+use sha2::{Digest, Sha256};
+use store::{Store, DEFAULT_PROFILE};
+use tracing::{info, info_span, level_filters::LevelFilter};
+use tracing_subscriber::EnvFilter;
+use workers::{Job, Limits, Verdict, LIMITS};
+
+mod bench;
+mod days;
+mod fetch;
+mod input;
+mod ledger;
+mod options;
+mod output;
+mod samples;
+mod scaffold;
+mod store;
+mod stress;
+mod watch;
+mod workers;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2023 runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Log more detail to stderr: -v for info, -vv for debug, -vvv for trace. RUST_LOG takes
+    /// precedence, for filtering by crate (e.g. RUST_LOG=d12=trace).
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+
+    /// Where fetched inputs are kept. Defaults to $XDG_CACHE_HOME/aoc or ~/.cache/aoc.
+    #[arg(long, global = true, env = "AOC_CACHE_DIR")]
+    cache_dir: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single day, or every day with --all.
+    Run(RunArgs),
+    /// Time each day's parse and parts over the real inputs, and compare against a baseline.
+    Bench(bench::BenchArgs),
+    /// Create a crate for a new day and register it with the workspace and runner.
+    New(scaffold::NewArgs),
+    /// Download a day's input into the input store, unless it's already there.
+    Fetch(fetch::FetchArgs),
+    /// Check solutions against slow reference answers on randomly generated inputs.
+    Stress(stress::StressArgs),
+    /// Check a day's answers on its samples, or every day's with no --day.
+    Samples(samples::SamplesArgs),
+    /// Rebuild and re-run a day whenever its code or input changes, checking the samples first.
+    Watch(watch::WatchArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to run (1-25).
+    #[arg(long, required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u32>,
+
+    /// Part to run. Runs both parts if omitted.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Input file, or - for standard input. Defaults to the profile's input from the input store.
+    #[arg(long, requires = "day")]
+    input: Option<PathBuf>,
+
+    /// Whose input to run on. Only the default profile's answers are checked against the ledger, and
+    /// only if the input is the one they're for.
+    #[arg(long, default_value = DEFAULT_PROFILE, conflicts_with = "input")]
+    profile: String,
+
+    /// Run every day, each in its own process, stopping any that go over their budget.
+    #[arg(long, conflicts_with = "options")]
+    all: bool,
+
+    /// Days to run at once with --all. Defaults to the number of CPUs.
+    #[arg(short, long, conflicts_with = "day", value_parser = clap::value_parser!(u32).range(1..))]
+    jobs: Option<u32>,
+
+    /// Seconds each day may run for with --all, instead of its limit in limits.toml.
+    #[arg(long, conflicts_with = "day")]
+    timeout: Option<u64>,
+
+    /// MiB of memory each day may use with --all, instead of its limit in limits.toml.
+    #[arg(long, conflicts_with = "day")]
+    memory: Option<u64>,
+
+    /// Record the answers in the ledger as accepted.
+    #[arg(long, conflicts_with = "input")]
+    accept: bool,
+
+    /// How to print the answers.
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Save pictures of what the grid days are doing under DIR: single images, and directories of
+    /// numbered frames for the simulations.
+    #[arg(long, value_name = "DIR")]
+    render: Option<PathBuf>,
+
+    /// Image format for --render.
+    #[arg(long, value_enum, default_value_t = RenderFormat::Png)]
+    render_format: RenderFormat,
+
+    /// Set an option of the day, e.g. -o part2=german for day 1, overriding options.toml.
+    #[arg(
+        short = 'o',
+        long = "option",
+        value_name = "KEY=VALUE",
+        requires = "day"
+    )]
+    options: Vec<Options>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum RenderFormat {
+    Png,
+    Ppm,
+}
+
+impl From<RenderFormat> for ImageFormat {
+    fn from(format: RenderFormat) -> Self {
+        match format {
+            RenderFormat::Png => Self::Png,
+            RenderFormat::Ppm => Self::Ppm,
+        }
+    }
+}
+
+fn init_logging(verbose: u8) {
+    let level = match verbose {
+        0 => LevelFilter::WARN,
+        1 => LevelFilter::INFO,
+        2 => LevelFilter::DEBUG,
+        _ => LevelFilter::TRACE,
+    };
+
+    let filter = EnvFilter::builder()
+        .with_default_directive(level.into())
+        .from_env_lossy();
+
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .init();
+}
+
+// `day` with `options` applied, if there are any.
+fn configure(day: u32, options: &Options) -> Result<Option<Configured>, String> {
+    if options.is_empty() {
+        return Ok(None);
+    }
+
+    match days::configure(day) {
+        Some(c) => c
+            .configure(options)
+            .map(Some)
+            .map_err(|e| format!("Day {day}: {e}")),
+        None => Err(format!("Day {day} takes no options")),
+    }
+}
+
+// Run `parts` of `day` on `input`, configured if it has options. Errors are ready to show to the
+// user.
+fn run_day(
+    day: &days::Day,
+    configured: Option<&Configured>,
+    parts: &[u8],
+    input: &Path,
+) -> Result<DayRun, String> {
+    let _span = info_span!("day", day = day.day).entered();
+
+    let (solution, stream, scan) = match configured {
+        Some(c) => (
+            &*c.solution,
+            c.stream.as_deref(),
+            c.scan.as_deref().filter(|s| s.scanning()),
+        ),
+        None => (day.solution, days::stream(day.day), days::scan(day.day)),
+    };
+
+    // Leave out the parts with nothing to run yet.
+    let solved: Vec<u8> = parts
+        .iter()
+        .copied()
+        .filter(|p| solution.solved(*p))
+        .collect();
+    if solved.is_empty() {
+        return Err(format!("Day {} isn't solved yet", day.day));
+    }
+    for part in parts.iter().filter(|p| !solved.contains(p)) {
+        eprintln!("Day {}, part {part} isn't solved yet", day.day);
+    }
+    let parts = &solved[..];
+
+    if let Some(scan) = scan {
+        return scan_day(day, scan, parts, input);
+    }
+    if let Some(stream) = stream {
+        return stream_day(day, stream, parts, input);
+    }
+
+    let input_str =
+        Input::load(input).map_err(|e| format!("Day {}: {}: {e}", day.day, input.display()))?;
+    let input_sha256 = format!("{:x}", Sha256::digest(input_str.as_bytes()));
+
+    info!("parsing {}", input.display());
+    let start = Instant::now();
+    let model = solution
+        .parse(&input_str)
+        .map_err(|e| e.in_file(day.day, input).render(&input_str))?;
+    let parse = start.elapsed();
+
+    // On stderr, out of the way of the answers.
+    if let Some(report) = solution.report(model.as_ref()) {
+        eprint!("{report}");
+    }
+
+    let mut run = DayRun {
+        input_sha256,
+        parse,
+        parts: Vec::new(),
+        failed: Vec::new(),
+    };
+    for &part in parts {
+        let _span = info_span!("part", part).entered();
+        let start = Instant::now();
+        match solution.part(model.as_ref(), part) {
+            Ok(answer) => run.parts.push(PartRun {
+                part,
+                answer,
+                solve: start.elapsed(),
+            }),
+            Err(e) => run
+                .failed
+                .push((part, e.in_file(day.day, input).render(&input_str))),
+        }
+    }
+
+    Ok(run)
+}
+
+// Like `run_day`, for a day that works out its parts as it reads the input a line at a time. The
+// time taken is all put down to parsing, since there's no telling the parts apart.
+fn stream_day(
+    day: &days::Day,
+    stream: &dyn DynStream,
+    parts: &[u8],
+    input: &Path,
+) -> Result<DayRun, String> {
+    let read_error = |e| format!("Day {}: {}: {e}", day.day, input.display());
+
+    info!("streaming {}", input.display());
+    let start = Instant::now();
+    let mut lines = input::open(input).map_err(read_error)?;
+    let render = |e| match e {
+        StreamError::Read(e) => read_error(e),
+        StreamError::Parse { error, line } => error.in_file(day.day, input).render_line(&line),
+    };
+    let answers = stream.stream(&mut lines, parts).map_err(render)?;
+    let parse = start.elapsed();
+
+    let mut run = DayRun {
+        input_sha256: lines.into_inner().sha256(),
+        parse,
+        parts: Vec::new(),
+        failed: Vec::new(),
+    };
+    for (&part, answer) in parts.iter().zip(answers) {
+        match answer {
+            Ok(answer) => run.parts.push(PartRun {
+                part,
+                answer,
+                solve: Duration::ZERO,
+            }),
+            Err(e) => run.failed.push((part, render(e))),
+        }
+    }
+
+    Ok(run)
+}
+
+// Like `run_day`, for a day that works out each part straight from the input without a model. The
+// time taken is all put down to the parts.
+fn scan_day(
+    day: &days::Day,
+    scan: &dyn DynScan,
+    parts: &[u8],
+    input: &Path,
+) -> Result<DayRun, String> {
+    let input_str =
+        Input::load(input).map_err(|e| format!("Day {}: {}: {e}", day.day, input.display()))?;
+    let input_sha256 = format!("{:x}", Sha256::digest(input_str.as_bytes()));
+
+    info!("scanning {}", input.display());
+    let mut run = DayRun {
+        input_sha256,
+        parse: Duration::ZERO,
+        parts: Vec::new(),
+        failed: Vec::new(),
+    };
+    for &part in parts {
+        let _span = info_span!("part", part).entered();
+        let start = Instant::now();
+        match scan.scan(&input_str, part) {
+            Ok(answer) => run.parts.push(PartRun {
+                part,
+                answer,
+                solve: start.elapsed(),
+            }),
+            Err(e) => run
+                .failed
+                .push((part, e.in_file(day.day, input).render(&input_str))),
+        }
+    }
+
+    Ok(run)
+}
+
+// Run `selected` days in worker processes, so that one that panics or never finishes can't take
+// the others down with it. Returns whether every day passed.
+fn run_workers(
+    args: &RunArgs,
+    selected: &[&days::Day],
+    parts: &[u8],
+    store: &Store,
+    verbose: u8,
+    mut report: impl FnMut(u32, &Path, &DayRun) -> bool,
+) -> bool {
+    let limits = match Limits::load(Path::new(LIMITS)) {
+        Ok(limits) => limits,
+        Err(e) => {
+            eprintln!("Couldn't load limits: {e}");
+            return false;
+        }
+    };
+
+    let exe = match env::current_exe() {
+        Ok(exe) => exe,
+        Err(e) => {
+            eprintln!("Couldn't find the runner to start workers with: {e}");
+            return false;
+        }
+    };
+
+    let mut ok = true;
+    let mut jobs = Vec::new();
+    for day in selected {
+        match store.resolve(YEAR, day, &args.profile) {
+            Ok(input) => jobs.push(Job {
+                day: day.day,
+                input,
+                budget: limits.budget(day.day, args.timeout, args.memory),
+            }),
+            Err(e) => {
+                eprintln!("{e}");
+                ok = false;
+            }
+        }
+    }
+
+    let threads = match args.jobs {
+        Some(jobs) => jobs as usize,
+        None => thread::available_parallelism().map_or(1, |n| n.get()),
+    };
+
+    // Workers run a single day on a given input, and leave checking the answers to us.
+    let command = |job: &Job| {
+        let mut command = process::Command::new(&exe);
+        command
+            .args([
+                "run",
+                "--format",
+                "json",
+                "--day",
+                &job.day.to_string(),
+                "--input",
+            ])
+            .arg(&job.input);
+        if let Some(part) = args.part {
+            command.args(["--part", &part.to_string()]);
+        }
+        if verbose > 0 {
+            command.arg(format!("-{}", "v".repeat(verbose as usize)));
+        }
+        if let Some(dir) = &args.render {
+            command.arg("--render").arg(dir).args([
+                "--render-format",
+                args.render_format.to_possible_value().unwrap().get_name(),
+            ]);
+        }
+        command
+    };
+
+    let rows = workers::run_all(&jobs, threads, command, |job, outcome| {
+        let outcome = match outcome {
+            Ok(outcome) => outcome,
+            Err(e) => {
+                output::error(args.format, job.day, parts, &job.input, &e);
+                return Verdict::Fail;
+            }
+        };
+
+        eprint!("{}", outcome.stderr);
+
+        let mut verdict = Verdict::Pass;
+        for record in outcome.records.iter().filter(|r| r.answer.is_none()) {
+            output::failed(args.format, record);
+            verdict = Verdict::Fail;
+        }
+
+        if let Some(run) = DayRun::from_records(&outcome.records) {
+            if !report(job.day, &job.input, &run) {
+                verdict = Verdict::Fail;
+            }
+        }
+
+        if let Some((failure, message)) = outcome.failure(job) {
+            let missing: Vec<u8> = parts
+                .iter()
+                .copied()
+                .filter(|p| outcome.records.iter().all(|r| r.part != *p))
+                .collect();
+            output::error(args.format, job.day, &missing, &job.input, &message);
+            verdict = failure;
+        }
+
+        verdict
+    });
+
+    if args.format == Format::Text {
+        workers::print_summary(&rows);
+    }
+
+    ok && rows.iter().all(|r| r.verdict == Verdict::Pass)
+}
+
+fn run(args: RunArgs, store: &Store, verbose: u8) -> ExitCode {
+    if let Some(dir) = &args.render {
+        render::render_to(dir.clone(), args.render_format.into());
+    }
+
+    let selected: Vec<&days::Day> = match args.day {
+        Some(day) => match days::get(day) {
+            Some(d) => vec![d],
+            None => {
+                eprintln!("No solution for day {day}");
+                return ExitCode::FAILURE;
+            }
+        },
+        None => days::solved(store, &args.profile),
+    };
+
+    let parts: Vec<u8> = match args.part {
+        Some(p) => vec![p],
+        None => vec![1, 2],
+    };
+
+    // The ledger only knows the answers for our own inputs.
+    let real_input = args.input.is_none() && args.profile == DEFAULT_PROFILE;
+    if args.accept && !real_input {
+        eprintln!("Only answers for the {DEFAULT_PROFILE} profile go in the ledger");
+        return ExitCode::FAILURE;
+    }
+
+    let options_file = match OptionsFile::load(Path::new(OPTIONS)) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("Couldn't load options: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let overrides = args.options.iter().fold(Options::new(), |mut all, o| {
+        all.extend(o);
+        all
+    });
+    let options = |day: u32| options_file.options(day, &overrides);
+
+    let ledger_path = Path::new(LEDGER);
+    let mut ledger = match Ledger::load(ledger_path) {
+        Ok(ledger) => ledger,
+        Err(e) => {
+            eprintln!("Couldn't load ledger: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // Check and print one day's answers, and record them if accepting. Returns whether they're
+    // all good.
+    let mut report = |day: u32, input: &Path, run: &DayRun| {
+        let mut ok = true;
+        let recorded = real_input && options(day).is_empty();
+        // Our profile's input may have been fetched from another account than the answers were.
+        let checked = recorded && ledger.is_for(day, &run.input_sha256);
+
+        for p in [1, 2] {
+            if let Some((_, e)) = run.failed.iter().find(|(f, _)| *f == p) {
+                output::error(args.format, day, &[p], input, e);
+                ok = false;
+            }
+            let Some(part) = run.parts.iter().find(|r| r.part == p) else {
+                continue;
+            };
+
+            let status = checked.then(|| ledger.check(day, part.part, &part.answer));
+            // Accepting a new answer is how a regression gets fixed.
+            ok &= args.accept || !matches!(status, Some(Status::Regression { .. }));
+
+            output::part(args.format, day, input, run, part, status.as_ref());
+
+            if args.accept && recorded {
+                ledger.accept(day, part.part, &part.answer, &run.input_sha256);
+            }
+        }
+
+        ok
+    };
+
+    let ok = if args.all {
+        run_workers(&args, &selected, &parts, store, verbose, &mut report)
+    } else {
+        let mut ok = true;
+
+        for day in selected {
+            let input = match &args.input {
+                Some(input) => Ok(input.clone()),
+                None => store.resolve(YEAR, day, &args.profile),
+            };
+            let input = match input {
+                Ok(input) => input,
+                Err(e) => {
+                    eprintln!("{e}");
+                    ok = false;
+                    continue;
+                }
+            };
+
+            let configured = match configure(day.day, &options(day.day)) {
+                Ok(configured) => configured,
+                Err(e) => {
+                    output::error(args.format, day.day, &parts, &input, &e);
+                    ok = false;
+                    continue;
+                }
+            };
+
+            match run_day(day, configured.as_ref(), &parts, &input) {
+                Ok(run) => ok &= report(day.day, &input, &run),
+                Err(e) => {
+                    output::error(args.format, day.day, &parts, &input, &e);
+                    ok = false;
+                }
+            }
+        }
+
+        ok
+    };
+
+    if args.accept {
+        if let Err(e) = ledger.save(ledger_path) {
+            eprintln!("Couldn't save ledger: {e}");
+            return ExitCode::FAILURE;
+        }
+    }
+
+    if ok {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    init_logging(cli.verbose);
+
+    let store = || match Store::new(cli.cache_dir.clone()) {
+        Ok(store) => Some(store),
+        Err(e) => {
+            eprintln!("{e}");
+            None
+        }
+    };
+
+    match cli.command {
+        Command::Run(args) => store().map_or(ExitCode::FAILURE, |s| run(args, &s, cli.verbose)),
+        Command::Bench(args) => store().map_or(ExitCode::FAILURE, |s| bench::bench(args, &s)),
+        Command::New(args) => scaffold::new_day(args),
+        Command::Fetch(args) => store().map_or(ExitCode::FAILURE, |s| fetch::fetch(args, &s)),
+        Command::Stress(args) => stress::stress(args),
+        Command::Samples(args) => samples::samples(args),
+        Command::Watch(args) => {
+            store().map_or(ExitCode::FAILURE, |s| watch::watch(args, &s, cli.verbose))
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use std::{fs, path::Path};
+
+use common::Options;
+use serde::Deserialize;
+use toml::Table;
+
+pub const OPTIONS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/options.toml");
+
+#[derive(Deserialize)]
+struct DayOptions {
+    day: u32,
+    #[serde(flatten)]
+    options: Table,
+}
+
+/// The options each day runs with, from the options file.
+#[derive(Deserialize)]
+pub struct OptionsFile {
+    #[serde(default)]
+    day: Vec<DayOptions>,
+}
+
+impl OptionsFile {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let options = fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+        toml::from_str(&options).map_err(|e| format!("{}: {e}", path.display()))
+    }
+
+    /// The options for `day`, with `overrides` from the command line replacing the file's.
+    pub fn options(&self, day: u32, overrides: &Options) -> Options {
+        let mut options = Options::new();
+
+        for (key, value) in self
+            .day
+            .iter()
+            .filter(|d| d.day == day)
+            .flat_map(|d| &d.options)
+        {
+            match value.as_str() {
+                Some(s) => options.set(key, s),
+                None => options.set(key, &value.to_string()),
+            }
+        }
+        options.extend(overrides);
+
+        options
+    }
+}
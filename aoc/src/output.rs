@@ -0,0 +1,157 @@
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::ledger::Status;
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// One line per part, for people.
+    Text,
+    /// One JSON record per part, one per line, for scripts.
+    Json,
+}
+
+/// The result of running one part.
+pub struct PartRun {
+    pub part: u8,
+    pub answer: String,
+    pub solve: Duration,
+}
+
+/// The result of running one day.
+pub struct DayRun {
+    pub input_sha256: String,
+    pub parse: Duration,
+    pub parts: Vec<PartRun>,
+    /// The parts the input had no answer to, with the error ready to show.
+    pub failed: Vec<(u8, String)>,
+}
+
+impl DayRun {
+    /// Rebuild a run from the records it was printed as. `None` if none of them have an answer.
+    pub fn from_records(records: &[Record]) -> Option<Self> {
+        let first = records.iter().find(|r| r.answer.is_some())?;
+
+        Some(Self {
+            input_sha256: first.input_sha256.clone().unwrap_or_default(),
+            parse: Duration::from_nanos(first.parse_ns.unwrap_or(0)),
+            parts: records
+                .iter()
+                .filter_map(|r| {
+                    Some(PartRun {
+                        part: r.part,
+                        answer: r.answer.clone()?,
+                        solve: Duration::from_nanos(r.solve_ns.unwrap_or(0)),
+                    })
+                })
+                .collect(),
+            // Failed parts are passed on from the records as they are.
+            failed: Vec::new(),
+        })
+    }
+}
+
+/// What `--format json` prints for each part.
+#[derive(Serialize, Deserialize)]
+pub struct Record {
+    pub day: u32,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub input: PathBuf,
+    pub input_sha256: Option<String>,
+    pub parse_ns: Option<u64>,
+    pub solve_ns: Option<u64>,
+    /// `correct`, `unverified`, `regression` or `unknown`, or `error` if the day didn't run.
+    pub status: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl Record {
+    fn print(&self) {
+        println!("{}", serde_json::to_string(self).unwrap());
+    }
+}
+
+fn status_name(status: Option<&Status>) -> &'static str {
+    match status {
+        Some(Status::Correct) => "correct",
+        Some(Status::Unverified) => "unverified",
+        Some(Status::Regression { .. }) => "regression",
+        Some(Status::Unknown) | None => "unknown",
+    }
+}
+
+/// Print one part's answer. `status` is `None` when the answer wasn't checked against the ledger.
+pub fn part(
+    format: Format,
+    day: u32,
+    input: &Path,
+    run: &DayRun,
+    part: &PartRun,
+    status: Option<&Status>,
+) {
+    match format {
+        Format::Text => match status {
+            Some(status) => println!("Day {day}, part {}: {} {status}", part.part, part.answer),
+            None => println!("Day {day}, part {}: {}", part.part, part.answer),
+        },
+        Format::Json => {
+            let expected = match status {
+                Some(Status::Regression { expected }) => Some(expected.clone()),
+                _ => None,
+            };
+
+            Record {
+                day,
+                part: part.part,
+                answer: Some(part.answer.clone()),
+                input: input.to_path_buf(),
+                input_sha256: Some(run.input_sha256.clone()),
+                parse_ns: Some(run.parse.as_nanos() as u64),
+                solve_ns: Some(part.solve.as_nanos() as u64),
+                status: status_name(status).to_string(),
+                expected,
+                error: None,
+            }
+            .print();
+        }
+    }
+}
+
+/// Report a day that couldn't run, e.g. because its input didn't parse.
+pub fn error(format: Format, day: u32, parts: &[u8], input: &Path, error: &str) {
+    eprintln!("{}", error.trim_end());
+
+    if format == Format::Json {
+        for part in parts {
+            Record {
+                day,
+                part: *part,
+                answer: None,
+                input: input.to_path_buf(),
+                input_sha256: None,
+                parse_ns: None,
+                solve_ns: None,
+                status: "error".to_string(),
+                expected: None,
+                error: Some(error.trim_end().to_string()),
+            }
+            .print();
+        }
+    }
+}
+
+/// Pass on the record of a part that failed in a worker, whose error the worker already reported.
+pub fn failed(format: Format, record: &Record) {
+    if format == Format::Json {
+        record.print();
+    }
+}
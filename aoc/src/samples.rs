@@ -0,0 +1,163 @@
+use std::{fs, path::Path, process::ExitCode};
+
+use clap::Args;
+use serde::Deserialize;
+
+use crate::days;
+
+const MANIFEST: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/samples.toml");
+
+#[derive(Args)]
+pub struct SamplesArgs {
+    /// Day to check. Checks every day's samples if omitted.
+    #[arg(long)]
+    day: Option<u32>,
+
+    /// Part to check. Checks both parts if omitted.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+}
+
+#[derive(Deserialize)]
+struct Manifest {
+    sample: Vec<Sample>,
+}
+
+#[derive(Deserialize)]
+struct Sample {
+    day: u32,
+    file: String,
+    part1: Option<i64>,
+    part2: Option<i64>,
+}
+
+impl Sample {
+    fn expected(&self, part: u8) -> Option<i64> {
+        match part {
+            1 => self.part1,
+            2 => self.part2,
+            _ => unreachable!("Invalid part {part}"),
+        }
+    }
+
+    // Every mismatch between this sample's expected and actual answers for `parts`.
+    fn check(&self, parts: &[u8]) -> Vec<String> {
+        if parts.iter().all(|p| self.expected(*p).is_none()) {
+            return Vec::new();
+        }
+
+        let name = format!("Day {}, {}", self.day, self.file);
+        let Some(day) = days::get(self.day) else {
+            return vec![format!("{name}: no solution for day {}", self.day)];
+        };
+        let path = Path::new(day.input).with_file_name(&self.file);
+
+        let input = match fs::read_to_string(&path) {
+            Ok(input) => input,
+            Err(e) => return vec![format!("{name}: {}: {e}", path.display())],
+        };
+        let model = match day.solution.parse(&input) {
+            Ok(model) => model,
+            Err(e) => return vec![format!("{name}: {}", e.in_file(self.day, &path))],
+        };
+
+        let mut failures = Vec::new();
+
+        for &part in parts {
+            let Some(expected) = self.expected(part) else {
+                continue;
+            };
+
+            match day.solution.part(model.as_ref(), part) {
+                Ok(actual) if actual == expected.to_string() => {}
+                Ok(actual) => failures.push(format!(
+                    "{name}, part {part}: expected {expected}, got {actual}"
+                )),
+                Err(e) => failures.push(format!(
+                    "{name}, part {part}: {}",
+                    e.in_file(self.day, &path)
+                )),
+            }
+        }
+
+        // The runner streams the days that can be, so check that way too.
+        if let Some(stream) = days::stream(self.day) {
+            let parts: Vec<u8> = parts
+                .iter()
+                .copied()
+                .filter(|p| self.expected(*p).is_some())
+                .collect();
+
+            match stream.stream(&mut input.as_bytes(), &parts) {
+                Ok(answers) => {
+                    for (part, actual) in parts.into_iter().zip(answers) {
+                        let expected = self.expected(part).unwrap();
+                        match actual {
+                            Ok(actual) if actual == expected.to_string() => {}
+                            Ok(actual) => failures.push(format!(
+                                "{name}, part {part} streamed: expected {expected}, got {actual}"
+                            )),
+                            Err(e) => failures.push(format!("{name}, part {part} streamed: {e}")),
+                        }
+                    }
+                }
+                Err(e) => failures.push(format!("{name}, streamed: {e}")),
+            }
+        }
+
+        failures
+    }
+}
+
+fn load() -> Result<Vec<Sample>, String> {
+    let manifest = fs::read_to_string(MANIFEST).map_err(|e| format!("{MANIFEST}: {e}"))?;
+    let manifest: Manifest = toml::from_str(&manifest).map_err(|e| format!("{MANIFEST}: {e}"))?;
+
+    Ok(manifest.sample)
+}
+
+pub fn samples(args: SamplesArgs) -> ExitCode {
+    let samples = match load() {
+        Ok(samples) => samples,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let parts: Vec<u8> = match args.part {
+        Some(p) => vec![p],
+        None => vec![1, 2],
+    };
+    let selected: Vec<&Sample> = samples
+        .iter()
+        .filter(|s| args.day.is_none_or(|d| d == s.day))
+        .filter(|s| parts.iter().any(|p| s.expected(*p).is_some()))
+        .collect();
+
+    // Check everything before failing, so one broken day doesn't hide another.
+    let failures: Vec<String> = selected.iter().flat_map(|s| s.check(&parts)).collect();
+    for f in &failures {
+        println!("{f}");
+    }
+
+    if failures.is_empty() {
+        println!("{} samples passed", selected.len());
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::load;
+
+    #[test]
+    fn samples() {
+        let samples = load().unwrap();
+
+        let failures: Vec<String> = samples.iter().flat_map(|s| s.check(&[1, 2])).collect();
+        assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+    }
+}
@@ -0,0 +1,284 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use clap::Args;
+use toml_edit::{value, DocumentMut, InlineTable};
+
+use crate::days::YEAR;
+
+const RUNNER_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+const CARGO_TEMPLATE: &str = include_str!("../template/Cargo.toml.tmpl");
+const LIB_TEMPLATE: &str = include_str!("../template/lib.rs");
+const P1_TEMPLATE: &str = include_str!("../template/p1.rs");
+const P2_TEMPLATE: &str = include_str!("../template/p2.rs");
+
+#[derive(Args)]
+pub struct NewArgs {
+    /// Day to create (1-25).
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: u32,
+
+    /// Year of the puzzle, for the link in the generated crate.
+    #[arg(long, default_value_t = YEAR)]
+    year: u32,
+
+    /// Restore the day's missing files if its crate already exists. Files changed from the
+    /// template, inputs and samples are kept.
+    #[arg(long)]
+    force: bool,
+}
+
+impl NewArgs {
+    fn name(&self) -> String {
+        format!("d{:02}", self.day)
+    }
+
+    fn render(&self, template: &str) -> String {
+        template
+            .replace("{{name}}", &self.name())
+            .replace("{{Name}}", &format!("Day{:02}", self.day))
+            .replace("{{day}}", &self.day.to_string())
+            .replace("{{year}}", &self.year.to_string())
+    }
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+fn parse_toml(path: &Path) -> Result<DocumentMut, String> {
+    read(path)?
+        .parse::<DocumentMut>()
+        .map_err(|e| format!("{}: {e}", path.display()))
+}
+
+// Write the crate itself: manifest, code from the templates, and empty input and sample files.
+// Files already there are left alone, so regenerating a day can't lose work on it. Returns the
+// ones that have been changed from the template, which are kept as they are.
+fn create_crate(args: &NewArgs, dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let src = dir.join("src");
+    fs::create_dir_all(&src).map_err(|e| format!("{}: {e}", src.display()))?;
+
+    let mut kept = Vec::new();
+    for (path, template) in [
+        (dir.join("Cargo.toml"), CARGO_TEMPLATE),
+        (src.join("lib.rs"), LIB_TEMPLATE),
+        (src.join("p1.rs"), P1_TEMPLATE),
+        (src.join("p2.rs"), P2_TEMPLATE),
+    ] {
+        let contents = args.render(template);
+        if !path.exists() {
+            write(&path, &contents)?;
+        } else if read(&path)? != contents {
+            kept.push(path);
+        }
+    }
+
+    for file in ["input.txt", "test.txt"] {
+        let path = src.join(file);
+        if !path.exists() {
+            write(&path, "")?;
+        }
+    }
+
+    Ok(kept)
+}
+
+// Add the crate to the workspace members, after the last one that sorts before it.
+fn add_member(name: &str, workspace: &Path) -> Result<(), String> {
+    let mut doc = parse_toml(workspace)?;
+
+    let members = doc["workspace"]["members"]
+        .as_array_mut()
+        .ok_or_else(|| format!("{}: no workspace members", workspace.display()))?;
+
+    if members.iter().any(|m| m.as_str() == Some(name)) {
+        return Ok(());
+    }
+
+    let names: Vec<Option<&str>> = members.iter().map(|m| m.as_str()).collect();
+    let idx = names
+        .iter()
+        .rposition(|m| m.is_some_and(|m| m < name))
+        .map(|i| i + 1)
+        .unwrap_or(names.len());
+
+    // Match the one-member-per-line layout of the rest of the list.
+    let prefix = members
+        .get(0)
+        .and_then(|m| m.decor().prefix())
+        .and_then(|p| p.as_str())
+        .unwrap_or("")
+        .to_string();
+
+    members.insert(idx, name);
+    members.get_mut(idx).unwrap().decor_mut().set_prefix(prefix);
+
+    write(workspace, &doc.to_string())
+}
+
+// Make the runner depend on the crate.
+fn add_dependency(name: &str, manifest: &Path) -> Result<(), String> {
+    let mut doc = parse_toml(manifest)?;
+
+    let deps = doc["dependencies"]
+        .as_table_mut()
+        .ok_or_else(|| format!("{}: no dependencies", manifest.display()))?;
+
+    if !deps.contains_key(name) {
+        let mut dep = InlineTable::new();
+        dep.insert("path", format!("../{name}").into());
+        deps.insert(name, value(dep));
+        deps.sort_values();
+    }
+
+    write(manifest, &doc.to_string())
+}
+
+// Add the day to the runner's list of days, in order.
+fn register_day(args: &NewArgs, days_rs: &Path) -> Result<(), String> {
+    const ENTRY_START: &str = "    Day {\n        day: ";
+
+    let mut days = read(days_rs)?;
+
+    let mut insert_at = None;
+    for (idx, _) in days.match_indices(ENTRY_START) {
+        let rest = &days[idx + ENTRY_START.len()..];
+        let day: u32 = rest[..rest.find(',').unwrap_or(0)]
+            .parse()
+            .map_err(|_| format!("{}: can't read the day at byte {idx}", days_rs.display()))?;
+
+        if day == args.day {
+            return Ok(());
+        }
+        if day > args.day {
+            insert_at = Some(idx);
+            break;
+        }
+    }
+
+    let insert_at = match insert_at {
+        Some(idx) => idx,
+        None => days
+            .find("\n];")
+            .map(|i| i + 1)
+            .ok_or_else(|| format!("{}: can't find the end of DAYS", days_rs.display()))?,
+    };
+
+    let name = args.name();
+    let entry = format!(
+        "{ENTRY_START}{},\n        input: {name}::INPUT,\n        solution: &{name}::Day{:02},\n    }},\n",
+        args.day, args.day
+    );
+    days.insert_str(insert_at, &entry);
+
+    write(days_rs, &days)
+}
+
+// Give the day an entry in the sample manifest, to fill in with the sample's answers.
+fn add_sample(args: &NewArgs, samples: &Path) -> Result<(), String> {
+    let doc = parse_toml(samples)?;
+
+    let has_sample = doc["sample"].as_array_of_tables().is_some_and(|s| {
+        s.iter()
+            .any(|s| s["day"].as_integer() == Some(args.day as i64))
+    });
+    if has_sample {
+        return Ok(());
+    }
+
+    let mut manifest = read(samples)?;
+    manifest += &format!("\n[[sample]]\nday = {}\nfile = \"test.txt\"\n", args.day);
+
+    write(samples, &manifest)
+}
+
+fn scaffold(args: &NewArgs) -> Result<(), String> {
+    let runner = Path::new(RUNNER_DIR);
+    let root = runner.parent().unwrap();
+    let name = args.name();
+    let dir: PathBuf = root.join(&name);
+
+    if dir.exists() && !args.force {
+        return Err(format!(
+            "{} already exists, use --force to restore any files missing from it",
+            dir.display()
+        ));
+    }
+
+    let kept = create_crate(args, &dir)?;
+    add_member(&name, &root.join("Cargo.toml"))?;
+    add_dependency(&name, &runner.join("Cargo.toml"))?;
+    register_day(args, &runner.join("src/days.rs"))?;
+    add_sample(args, &runner.join("samples.toml"))?;
+
+    println!("Created {}", dir.display());
+    for path in kept {
+        println!("Kept {}, which has been changed", path.display());
+    }
+    println!(
+        "Put your input in {} and the example in {}",
+        dir.join("src/input.txt").display(),
+        dir.join("src/test.txt").display()
+    );
+
+    Ok(())
+}
+
+pub fn new_day(args: NewArgs) -> ExitCode {
+    match scaffold(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, process};
+
+    use super::{create_crate, NewArgs};
+    use crate::days::YEAR;
+
+    #[test]
+    fn force_keeps_changes() {
+        let args = NewArgs {
+            day: 26,
+            year: YEAR,
+            force: true,
+        };
+        let dir = env::temp_dir().join(format!("aoc-scaffold-test-{}", process::id()));
+        let src = dir.join("src");
+
+        assert_eq!(create_crate(&args, &dir), Ok(vec![]));
+        let template = fs::read_to_string(src.join("p1.rs")).unwrap();
+
+        // A solved part and an input, and a part deleted to be regenerated.
+        fs::write(src.join("lib.rs"), "// solved\n").unwrap();
+        fs::write(src.join("input.txt"), "1 2 3\n").unwrap();
+        fs::remove_file(src.join("p1.rs")).unwrap();
+
+        assert_eq!(create_crate(&args, &dir), Ok(vec![src.join("lib.rs")]));
+        assert_eq!(
+            fs::read_to_string(src.join("lib.rs")).unwrap(),
+            "// solved\n"
+        );
+        assert_eq!(
+            fs::read_to_string(src.join("input.txt")).unwrap(),
+            "1 2 3\n"
+        );
+        assert_eq!(fs::read_to_string(src.join("p1.rs")).unwrap(), template);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
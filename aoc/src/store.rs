@@ -0,0 +1,79 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use crate::days::Day;
+
+/// The profile for our own inputs, the ones the ledger has answers for.
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// Puzzle inputs, keyed by year, day and profile (whose input it is).
+///
+/// Fetched inputs live under the cache directory as `<year>/<day>/<profile>.txt`. Inputs that ship
+/// with a day's crate are used when the cache doesn't have one: `src/input.txt` for the default
+/// profile, and `src/<profile>.txt` for anyone else's.
+pub struct Store {
+    dir: PathBuf,
+}
+
+impl Store {
+    /// A store in `dir`, or in the user's cache directory if `None`.
+    pub fn new(dir: Option<PathBuf>) -> Result<Self, String> {
+        let dir = match dir {
+            Some(dir) => dir,
+            None => env::var_os("XDG_CACHE_HOME")
+                .map(PathBuf::from)
+                .or_else(|| env::var_os("HOME").map(|h| Path::new(&h).join(".cache")))
+                .ok_or("Couldn't find a cache directory, pass --cache-dir")?
+                .join("aoc"),
+        };
+
+        Ok(Self { dir })
+    }
+
+    /// Where the cached input for `(year, day, profile)` lives, whether or not it's there.
+    pub fn path(&self, year: u32, day: u32, profile: &str) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("{day:02}"))
+            .join(format!("{profile}.txt"))
+    }
+
+    fn bundled(day: &Day, profile: &str) -> PathBuf {
+        if profile == DEFAULT_PROFILE {
+            PathBuf::from(day.input)
+        } else {
+            Path::new(day.input).with_file_name(format!("{profile}.txt"))
+        }
+    }
+
+    /// The input to use for `day`: the cached one if we have it, otherwise the bundled one.
+    pub fn resolve(&self, year: u32, day: &Day, profile: &str) -> Result<PathBuf, String> {
+        let cached = self.path(year, day.day, profile);
+        if cached.exists() {
+            return Ok(cached);
+        }
+
+        let bundled = Self::bundled(day, profile);
+        if bundled.exists() {
+            return Ok(bundled);
+        }
+
+        let fetch = match profile {
+            DEFAULT_PROFILE => format!("aoc fetch --day {}", day.day),
+            _ => format!("aoc fetch --day {} --profile {profile}", day.day),
+        };
+        Err(format!(
+            "Day {}: no input for profile {profile:?}, fetch it with `{fetch}` or put it in {}",
+            day.day,
+            cached.display()
+        ))
+    }
+
+    /// Whether `day` has a non-empty input to run on. Days fresh from `aoc new` have an empty one.
+    pub fn has_input(&self, year: u32, day: &Day, profile: &str) -> bool {
+        self.resolve(year, day, profile)
+            .is_ok_and(|p| fs::metadata(p).is_ok_and(|m| m.len() > 0))
+    }
+}
@@ -0,0 +1,150 @@
+use std::{
+    panic::{self, AssertUnwindSafe},
+    process::ExitCode,
+    time::Instant,
+};
+
+use clap::Args;
+use common::{DynGenerate, Rng};
+
+#[derive(Args)]
+pub struct StressArgs {
+    /// Day to test. Tests every day with a generator if omitted.
+    #[arg(long)]
+    day: Option<u32>,
+
+    /// Inputs to generate for each day.
+    #[arg(long, default_value_t = 1000)]
+    cases: u64,
+
+    /// Seed for the first input. Each input after it uses the next seed, so any failure can be
+    /// repeated with --seed and --cases 1.
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// How big to make the inputs. Bigger inputs find more bugs, but are slower to check and give
+    /// the references up more often.
+    #[arg(long, default_value_t = 4)]
+    size: usize,
+}
+
+/// Days with an input generator, to test against their references.
+const GENERATORS: &[(u32, &dyn DynGenerate)] = &[
+    (10, &d10::Day10),
+    (12, &d12::Day12),
+    (13, &d13::Day13),
+    (19, &d19::Day19),
+    (20, &d20::Day20),
+    (22, &d22::Day22),
+];
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "panicked".to_string())
+}
+
+// Check the solution against the references on `input`. Returns which parts had a reference to
+// check against, or what went wrong.
+fn check(generator: &dyn DynGenerate, input: &str) -> Result<[bool; 2], String> {
+    let model = generator
+        .parse(input)
+        .map_err(|e| format!("doesn't parse:\n{}", e.render(input)))?;
+
+    let mut checked = [false; 2];
+    for part in [1, 2] {
+        let Some(expected) = generator.reference(input, part) else {
+            continue;
+        };
+
+        let actual = panic::catch_unwind(AssertUnwindSafe(|| generator.part(model.as_ref(), part)))
+            .map_err(|p| format!("part {part} panicked: {}", panic_message(p.as_ref())))?
+            .map_err(|e| format!("part {part} has no answer:\n{}", e.render(input)))?;
+        if actual != expected {
+            return Err(format!("part {part}: expected {expected}, got {actual}"));
+        }
+
+        checked[part as usize - 1] = true;
+    }
+
+    Ok(checked)
+}
+
+// Run `cases` generated inputs starting at `seed`. Returns how many cases checked each part, or
+// the seed and input of the first failure with what went wrong.
+fn stress_day(
+    generator: &dyn DynGenerate,
+    seed: u64,
+    cases: u64,
+    size: usize,
+) -> Result<[u64; 2], (u64, String, String)> {
+    let mut checked = [0; 2];
+
+    for seed in seed..seed + cases {
+        let input = generator.generate(&mut Rng::new(seed), size);
+        let parts = check(generator, &input).map_err(|e| (seed, e, input))?;
+
+        for (count, part) in checked.iter_mut().zip(parts) {
+            *count += part as u64;
+        }
+    }
+
+    Ok(checked)
+}
+
+pub fn stress(args: StressArgs) -> ExitCode {
+    let generators: Vec<_> = GENERATORS
+        .iter()
+        .filter(|(day, _)| args.day.is_none_or(|d| d == *day))
+        .collect();
+    if generators.is_empty() {
+        eprintln!("No generator for day {}", args.day.unwrap());
+        return ExitCode::FAILURE;
+    }
+
+    // Panics are reported with the case that caused them, so don't also print them as they happen.
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut failed = false;
+    for (day, generator) in generators {
+        let start = Instant::now();
+
+        match stress_day(*generator, args.seed, args.cases, args.size) {
+            Ok([p1, p2]) => println!(
+                "Day {day}: {} cases, part 1 checked {p1}, part 2 checked {p2} ({:.2?})",
+                args.cases,
+                start.elapsed()
+            ),
+            Err((seed, error, input)) => {
+                failed = true;
+                println!("Day {day}, seed {seed}: {error}");
+                eprintln!("{input}");
+            }
+        }
+    }
+
+    panic::set_hook(hook);
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{stress_day, GENERATORS};
+
+    #[test]
+    fn generated() {
+        for (day, generator) in GENERATORS {
+            if let Err((seed, error, input)) = stress_day(*generator, 0, 50, 3) {
+                panic!("Day {day}, seed {seed}: {error}\n{input}");
+            }
+        }
+    }
+}
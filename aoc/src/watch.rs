@@ -0,0 +1,281 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    env,
+    ffi::OsStr,
+    io::{self, ErrorKind},
+    path::{Path, PathBuf},
+    process::{Command, ExitCode, Stdio},
+    thread,
+    time::Duration,
+};
+
+use clap::Args;
+use inotify::{Inotify, WatchDescriptor, WatchMask};
+
+use crate::{
+    days::{self, Day, YEAR},
+    ledger::{Ledger, LEDGER},
+    output::Record,
+    store::{Store, DEFAULT_PROFILE},
+};
+
+#[derive(Args)]
+pub struct WatchArgs {
+    /// Day to watch (1-25).
+    #[arg(long)]
+    day: u32,
+
+    /// Part to run. Runs both parts if omitted.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Whose input to run on. Only the default profile's answers are checked against the ledger, and
+    /// only if the input is the one they're for.
+    #[arg(long, default_value = DEFAULT_PROFILE)]
+    profile: String,
+}
+
+const WORKSPACE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+// Editors often save in several steps, so wait until changes stop for this long before rebuilding.
+const SETTLE: Duration = Duration::from_millis(200);
+
+// The files to re-run on changes to.
+struct Watched {
+    inotify: Inotify,
+    // Every file in these directories.
+    dirs: HashMap<WatchDescriptor, PathBuf>,
+    // Just the input in its directory, since the store keeps other inputs next to it.
+    input: (WatchDescriptor, PathBuf),
+}
+
+impl Watched {
+    fn new(day: &Day, input: &Path) -> io::Result<Self> {
+        let inotify = Inotify::init()?;
+        // Saves, and editors renaming a new version into place.
+        let mask = WatchMask::CLOSE_WRITE | WatchMask::MOVED_TO;
+
+        // The day's crate and its src directory, since watches don't cover subdirectories.
+        let src = Path::new(day.input).parent().unwrap();
+        let mut dirs = HashMap::new();
+        for dir in [src.parent().unwrap(), src] {
+            dirs.insert(inotify.watches().add(dir, mask)?, dir.to_path_buf());
+        }
+
+        let input_dir = input.parent().unwrap();
+        let input = (inotify.watches().add(input_dir, mask)?, input.to_path_buf());
+
+        Ok(Self {
+            inotify,
+            dirs,
+            input,
+        })
+    }
+
+    // Block until something changes, and has stopped changing for a moment. Returns what changed.
+    fn wait(&mut self) -> io::Result<BTreeSet<PathBuf>> {
+        let mut buffer = [0; 4096];
+        let mut changed = BTreeSet::new();
+
+        while changed.is_empty() {
+            let mut events = Vec::new();
+            for e in self.inotify.read_events_blocking(&mut buffer)? {
+                events.extend(e.name.map(|n| (e.wd, n.to_os_string())));
+            }
+
+            loop {
+                thread::sleep(SETTLE);
+                match self.inotify.read_events(&mut buffer) {
+                    Ok(more) => {
+                        for e in more {
+                            events.extend(e.name.map(|n| (e.wd, n.to_os_string())));
+                        }
+                    }
+                    Err(e) if e.kind() == ErrorKind::WouldBlock => break,
+                    Err(e) => return Err(e),
+                }
+            }
+
+            // Temporary files written while saving are gone by now.
+            for (wd, name) in events {
+                changed.extend(self.watched(&wd, &name).filter(|p| p.exists()));
+            }
+        }
+
+        Ok(changed)
+    }
+
+    // The path of a file that changed, unless it's one we don't care about.
+    fn watched(&self, wd: &WatchDescriptor, name: &OsStr) -> Option<PathBuf> {
+        // The bundled input is in src, and watching a directory twice gives the same descriptor,
+        // so check the crate's directories first.
+        if let Some(dir) = self.dirs.get(wd) {
+            // Skip editors' swap and backup files.
+            let n = name.to_string_lossy();
+            let scratch = n.starts_with('.') || n.ends_with('~') || n.ends_with(".swp");
+            return (!scratch).then(|| dir.join(name));
+        }
+
+        (*wd == self.input.0 && self.input.1.file_name() == Some(name))
+            .then(|| self.input.1.clone())
+    }
+}
+
+// Rebuild the runner, so the next runs use the changed code. Compile errors go to the terminal.
+fn build() -> bool {
+    let cargo = env::var_os("CARGO").unwrap_or("cargo".into());
+    let mut command = Command::new(cargo);
+    command
+        .args(["build", "--quiet", "--package", "aoc"])
+        .current_dir(WORKSPACE);
+
+    // Build the same profile we were built with, which is the binary that gets replaced.
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+
+    command.status().is_ok_and(|s| s.success())
+}
+
+struct Watch<'a> {
+    args: &'a WatchArgs,
+    exe: PathBuf,
+    input: PathBuf,
+    verbose: u8,
+    // The last answer to each part, to compare new ones with.
+    previous: HashMap<u8, String>,
+}
+
+impl Watch<'_> {
+    // The freshly built runner with `args`, logging as verbosely as we were asked to.
+    fn runner(&self, args: &[&str]) -> Command {
+        let mut command = Command::new(&self.exe);
+        command
+            .args(args)
+            .args(["--day", &self.args.day.to_string()]);
+        if let Some(part) = self.args.part {
+            command.args(["--part", &part.to_string()]);
+        }
+        if self.verbose > 0 {
+            command.arg(format!("-{}", "v".repeat(self.verbose as usize)));
+        }
+
+        command
+    }
+
+    // Check the samples, and if they pass, run the real input and show how the answers changed.
+    fn run(&mut self) {
+        if !build() {
+            println!("Build failed");
+            return;
+        }
+
+        let samples = self.runner(&["samples"]).status();
+        if !samples.is_ok_and(|s| s.success()) {
+            println!("Samples failed, not running the real input");
+            return;
+        }
+
+        let output = self
+            .runner(&["run", "--format", "json"])
+            .arg("--input")
+            .arg(&self.input)
+            .stderr(Stdio::inherit())
+            .output();
+        let records: Vec<Record> = match output {
+            Ok(output) => String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .filter_map(|l| serde_json::from_str(l).ok())
+                .collect(),
+            Err(e) => {
+                println!("Couldn't run {}: {e}", self.exe.display());
+                return;
+            }
+        };
+
+        // Only our own input's answers are in the ledger.
+        let ledger = (self.args.profile == DEFAULT_PROFILE)
+            .then(|| Ledger::load(Path::new(LEDGER)).ok())
+            .flatten();
+
+        for r in records {
+            let Some(answer) = r.answer else {
+                println!("Day {}, part {}: failed", r.day, r.part);
+                continue;
+            };
+
+            // Streamed days put all their time down to parsing, so count it in with the part's.
+            let time = Duration::from_nanos(r.parse_ns.unwrap_or(0) + r.solve_ns.unwrap_or(0));
+            let status = ledger
+                .as_ref()
+                .filter(|l| {
+                    r.input_sha256
+                        .as_ref()
+                        .is_some_and(|sha| l.is_for(r.day, sha))
+                })
+                .map(|l| format!(" {}", l.check(r.day, r.part, &answer)))
+                .unwrap_or_default();
+            let was = match self.previous.get(&r.part) {
+                Some(p) if *p == answer => " (unchanged)".to_string(),
+                Some(p) => format!(" (was {p})"),
+                None => String::new(),
+            };
+
+            println!(
+                "Day {}, part {}: {answer}{status} in {time:.2?}{was}",
+                r.day, r.part
+            );
+            self.previous.insert(r.part, answer);
+        }
+    }
+}
+
+pub fn watch(args: WatchArgs, store: &Store, verbose: u8) -> ExitCode {
+    let Some(day) = days::get(args.day) else {
+        eprintln!("No solution for day {}", args.day);
+        return ExitCode::FAILURE;
+    };
+
+    let setup = || -> Result<(PathBuf, Watched, PathBuf), String> {
+        let input = store.resolve(YEAR, day, &args.profile)?;
+        let watched =
+            Watched::new(day, &input).map_err(|e| format!("Can't watch for changes: {e}"))?;
+        let exe = env::current_exe().map_err(|e| format!("Couldn't find the runner: {e}"))?;
+
+        Ok((input, watched, exe))
+    };
+    let (input, mut watched, exe) = match setup() {
+        Ok(setup) => setup,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut watch = Watch {
+        args: &args,
+        exe,
+        input,
+        verbose,
+        previous: HashMap::new(),
+    };
+
+    loop {
+        watch.run();
+
+        println!("\nWatching day {} for changes...", args.day);
+        let changed = match watched.wait() {
+            Ok(changed) => changed,
+            Err(e) => {
+                eprintln!("Can't watch for changes: {e}");
+                return ExitCode::FAILURE;
+            }
+        };
+
+        // Start each run on a clear screen, so the last run's logs don't need scrolling past.
+        print!("\x1b[2J\x1b[H");
+        for path in changed {
+            println!("Changed {}", path.display());
+        }
+    }
+}
@@ -0,0 +1,368 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    os::unix::process::{CommandExt, ExitStatusExt},
+    path::{Path, PathBuf},
+    process::{Command, ExitStatus, Stdio},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use serde::Deserialize;
+use tracing::debug;
+
+use crate::{input, output::Record};
+
+pub const LIMITS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/limits.toml");
+
+// How often to check on a running day, once it's been going for a while. Quick days are checked
+// more often, so their times aren't rounded up to this.
+const POLL: Duration = Duration::from_millis(10);
+
+const MIB: u64 = 1024 * 1024;
+
+#[derive(Deserialize)]
+struct DayLimits {
+    day: u32,
+    timeout_secs: Option<u64>,
+    memory_mib: Option<u64>,
+}
+
+/// How long each day may run for and how much memory it may use, from the limits file.
+#[derive(Deserialize)]
+pub struct Limits {
+    timeout_secs: u64,
+    memory_mib: u64,
+    #[serde(default)]
+    day: Vec<DayLimits>,
+}
+
+/// The budget of one day.
+#[derive(Clone, Copy)]
+pub struct Budget {
+    pub timeout: Duration,
+    pub memory: u64,
+}
+
+impl Limits {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let limits = fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+        toml::from_str(&limits).map_err(|e| format!("{}: {e}", path.display()))
+    }
+
+    /// The budget for `day`. `timeout` and `memory` (in MiB) override the file, if given.
+    pub fn budget(&self, day: u32, timeout: Option<u64>, memory: Option<u64>) -> Budget {
+        let own = self.day.iter().find(|d| d.day == day);
+
+        let timeout = timeout
+            .or(own.and_then(|d| d.timeout_secs))
+            .unwrap_or(self.timeout_secs);
+        let memory = memory
+            .or(own.and_then(|d| d.memory_mib))
+            .unwrap_or(self.memory_mib);
+
+        Budget {
+            timeout: Duration::from_secs(timeout),
+            memory: memory * MIB,
+        }
+    }
+}
+
+/// A day to run in a worker process.
+pub struct Job {
+    pub day: u32,
+    pub input: PathBuf,
+    pub budget: Budget,
+}
+
+/// How a worker process ended.
+pub enum Exit {
+    Finished(ExitStatus),
+    TimedOut,
+    OutOfMemory,
+}
+
+/// What a worker process did.
+pub struct Outcome {
+    pub exit: Exit,
+    pub records: Vec<Record>,
+    pub stderr: String,
+    pub elapsed: Duration,
+    /// Most memory it was seen using, if the OS tells us and it ran long enough to be looked at.
+    /// This counts whatever of a mapped input was read. Only for reporting: the budget is enforced
+    /// as a limit on the process.
+    pub peak: Option<u64>,
+}
+
+impl Outcome {
+    /// Why the day didn't finish, if it didn't.
+    pub fn failure(&self, job: &Job) -> Option<(Verdict, String)> {
+        match &self.exit {
+            Exit::TimedOut => Some((
+                Verdict::Timeout,
+                format!("Day {}: timed out after {:?}", job.day, job.budget.timeout),
+            )),
+            Exit::OutOfMemory => Some((
+                Verdict::OutOfMemory,
+                format!(
+                    "Day {}: ran out of its {} MiB of memory",
+                    job.day,
+                    job.budget.memory / MIB
+                ),
+            )),
+            // A parse error is reported by the worker itself, as a record.
+            Exit::Finished(status) if status.success() || !self.records.is_empty() => None,
+            // Rust exits with 101 when the main thread panics.
+            Exit::Finished(status) if status.code() == Some(101) => {
+                Some((Verdict::Panic, format!("Day {}: panicked", job.day)))
+            }
+            Exit::Finished(status) => Some((
+                Verdict::Panic,
+                format!("Day {}: crashed ({status})", job.day),
+            )),
+        }
+    }
+}
+
+/// How a day did, for the summary.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail,
+    Timeout,
+    OutOfMemory,
+    Panic,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(match self {
+            Verdict::Pass => "pass",
+            Verdict::Fail => "fail",
+            Verdict::Timeout => "timeout",
+            Verdict::OutOfMemory => "memory",
+            Verdict::Panic => "panic",
+        })
+    }
+}
+
+// Peak resident memory of a running process. Only Linux tells us.
+fn peak_memory(pid: u32) -> Option<u64> {
+    let status = fs::read_to_string(format!("/proc/{pid}/status")).ok()?;
+    let kib = status
+        .lines()
+        .find_map(|l| l.strip_prefix("VmHWM:"))?
+        .trim()
+        .strip_suffix("kB")?
+        .trim()
+        .parse::<u64>()
+        .ok()?;
+
+    Some(kib * 1024)
+}
+
+// Run `command` within `budget`, killing it if it runs out of time. Its memory is capped by the
+// OS, so it can't run away with the machine's between polls. The command prints JSON records.
+//
+// The cap is on address space, which a mapped input takes up all of however little is read, so
+// `mapped` bytes of input are allowed on top of the budget: that's for what the day allocates.
+fn supervise(mut command: Command, budget: Budget, mapped: u64) -> Result<Outcome, String> {
+    let cap = budget.memory.saturating_add(mapped);
+    let limit = libc::rlimit {
+        rlim_cur: cap,
+        rlim_max: cap,
+    };
+    // SAFETY: setrlimit is async-signal-safe, and the closure touches nothing else.
+    unsafe {
+        command.pre_exec(move || match libc::setrlimit(libc::RLIMIT_AS, &limit) {
+            0 => Ok(()),
+            _ => Err(io::Error::last_os_error()),
+        });
+    }
+
+    let start = Instant::now();
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Couldn't start a worker: {e}"))?;
+
+    // Drain the pipes as we go, so a chatty worker can't fill one up and block.
+    let drain = |mut pipe: Box<dyn Read + Send>| {
+        thread::spawn(move || {
+            let mut out = String::new();
+            pipe.read_to_string(&mut out).ok();
+            out
+        })
+    };
+    let stdout = drain(Box::new(child.stdout.take().unwrap()));
+    let stderr = drain(Box::new(child.stderr.take().unwrap()));
+
+    let mut peak = None;
+    let exit = loop {
+        thread::sleep((start.elapsed() / 10).clamp(Duration::from_millis(1), POLL));
+
+        peak = peak.max(peak_memory(child.id()));
+
+        if let Some(status) = child.try_wait().map_err(|e| e.to_string())? {
+            break Exit::Finished(status);
+        }
+
+        if start.elapsed() > budget.timeout {
+            child.kill().ok();
+            child.wait().ok();
+            break Exit::TimedOut;
+        }
+    };
+    let elapsed = start.elapsed();
+    let stderr = stderr.join().unwrap();
+
+    // An allocation over the limit fails, and Rust aborts saying so.
+    let exit = match exit {
+        Exit::Finished(status)
+            if status.signal() == Some(libc::SIGABRT)
+                && stderr.contains("memory allocation of") =>
+        {
+            Exit::OutOfMemory
+        }
+        exit => exit,
+    };
+
+    let records = stdout
+        .join()
+        .unwrap()
+        .lines()
+        .filter_map(|l| serde_json::from_str(l).ok())
+        .collect();
+
+    Ok(Outcome {
+        exit,
+        records,
+        stderr,
+        elapsed,
+        peak,
+    })
+}
+
+/// One line of the summary.
+pub struct Row {
+    pub day: u32,
+    pub verdict: Verdict,
+    pub elapsed: Duration,
+    pub peak: Option<u64>,
+}
+
+/// Run `jobs` in worker processes, up to `threads` at a time. `command` makes the command that
+/// runs a job, and `done` is called with each job's outcome, in order, and says how it did.
+pub fn run_all(
+    jobs: &[Job],
+    threads: usize,
+    command: impl Fn(&Job) -> Command + Sync,
+    mut done: impl FnMut(&Job, Result<Outcome, String>) -> Verdict,
+) -> Vec<Row> {
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+    let mut rows = Vec::new();
+
+    thread::scope(|s| {
+        for _ in 0..threads.clamp(1, jobs.len().max(1)) {
+            let tx = tx.clone();
+            let (next, command) = (&next, &command);
+            s.spawn(move || loop {
+                let idx = next.fetch_add(1, Ordering::Relaxed);
+                let Some(job) = jobs.get(idx) else {
+                    break;
+                };
+
+                debug!("starting day {}", job.day);
+                let mapped = input::mapped_len(&job.input);
+                tx.send((idx, supervise(command(job), job.budget, mapped)))
+                    .unwrap();
+            });
+        }
+        drop(tx);
+
+        // Report in day order, holding back anything that finishes before an earlier day.
+        let mut pending = BTreeMap::new();
+        for (idx, outcome) in rx {
+            pending.insert(idx, outcome);
+
+            while let Some(outcome) = pending.remove(&rows.len()) {
+                let job = &jobs[rows.len()];
+                let (elapsed, peak) = match &outcome {
+                    Ok(o) => (o.elapsed, o.peak),
+                    Err(_) => (Duration::ZERO, None),
+                };
+
+                rows.push(Row {
+                    day: job.day,
+                    verdict: done(job, outcome),
+                    elapsed,
+                    peak,
+                });
+            }
+        }
+    });
+
+    rows
+}
+
+pub fn print_summary(rows: &[Row]) {
+    println!(
+        "\n{:>3}  {:<7}  {:>10}  {:>12}",
+        "Day", "Result", "Time", "Memory"
+    );
+
+    for row in rows {
+        // A day that finishes before it's first looked at has no peak to show.
+        let peak = match row.peak {
+            Some(p) => format!("{:.1} MiB", p as f64 / MIB as f64),
+            None => "not measured".to_string(),
+        };
+        println!(
+            "{:>3}  {:<7}  {:>10}  {:>12}",
+            row.day,
+            row.verdict,
+            format!("{:.2?}", row.elapsed),
+            peak
+        );
+    }
+
+    let count = |f: fn(&Verdict) -> bool| rows.iter().filter(|r| f(&r.verdict)).count();
+    println!(
+        "\n{} passed, {} failed, {} timed out, {} out of memory, {} panicked",
+        count(|v| *v == Verdict::Pass),
+        count(|v| *v == Verdict::Fail),
+        count(|v| *v == Verdict::Timeout),
+        count(|v| *v == Verdict::OutOfMemory),
+        count(|v| *v == Verdict::Panic)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{Limits, LIMITS};
+    use crate::days;
+
+    #[test]
+    fn limits_load() {
+        let limits = Limits::load(Path::new(LIMITS)).unwrap();
+
+        for entry in &limits.day {
+            assert!(
+                days::get(entry.day).is_some(),
+                "Limits for day {}, which has no solution",
+                entry.day
+            );
+        }
+    }
+}
@@ -0,0 +1,5 @@
+use common::Unsolved;
+
+pub fn p2(_lines: &[String]) -> Unsolved {
+    Unsolved
+}
@@ -0,0 +1,197 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+/// Where in the input a [`ParseError`] happened. Both are 1-based.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Malformed puzzle input.
+///
+/// Parsers only know the slice of input they choked on, so they build the error from that with
+/// [`ParseError::at`]. The position is filled in once the error reaches code holding the whole
+/// input (see [`ParseError::locate`]), and the runner adds the day and file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: Option<u32>,
+    pub file: Option<PathBuf>,
+    pub position: Option<Position>,
+    pub expected: String,
+    /// Description of what was there instead, ready for display.
+    pub found: String,
+    // Address and length of the offending slice, resolved against the input by `locate`.
+    addr: usize,
+    len: usize,
+}
+
+impl ParseError {
+    /// `at` was not what we expected. It must be a slice of the input for the error to be located;
+    /// an empty slice means something was missing at that point.
+    pub fn at(at: &str, expected: impl Into<String>) -> Self {
+        let found = at.split('\n').next().unwrap_or_default();
+
+        Self {
+            day: None,
+            file: None,
+            position: None,
+            expected: expected.into(),
+            found: if found.is_empty() {
+                "end of line".to_string()
+            } else {
+                format!("{found:?}")
+            },
+            addr: at.as_ptr() as usize,
+            len: found.len(),
+        }
+    }
+
+    // Whether this error is at the very start of `s`.
+    pub(crate) fn is_at(&self, s: &str) -> bool {
+        self.addr == s.as_ptr() as usize
+    }
+
+    /// Something was missing after `s`.
+    pub fn after(s: &str, expected: impl Into<String>) -> Self {
+        Self::at(&s[s.len()..], expected)
+    }
+
+    /// Work out the line and column from the full `input` this error came from.
+    pub fn locate(mut self, input: &str) -> Self {
+        if self.position.is_some() {
+            return self;
+        }
+
+        let start = input.as_ptr() as usize;
+        let Some(offset) = self.addr.checked_sub(start).filter(|o| *o <= input.len()) else {
+            // Not a slice of this input, so we can't say where it came from.
+            return self;
+        };
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+
+        self.position = Some(Position {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        });
+
+        self
+    }
+
+    pub fn in_file(mut self, day: u32, file: &Path) -> Self {
+        self.day = Some(day);
+        self.file = Some(file.to_path_buf());
+        self
+    }
+
+    /// Work out the column from `line`, the `number`th line of the input, for errors from input
+    /// read a line at a time.
+    pub fn on_line(self, number: usize, line: &str) -> Self {
+        let mut e = self.locate(line);
+        let column = e.position.map(|p| p.column).unwrap_or(1);
+        e.position = Some(Position {
+            line: number,
+            column,
+        });
+        e
+    }
+
+    /// Render a diagnostic pointing at the offending text in `input`.
+    pub fn render(&self, input: &str) -> String {
+        let text = self
+            .position
+            .and_then(|p| input.split('\n').nth(p.line - 1))
+            .unwrap_or_default();
+
+        self.render_line(text)
+    }
+
+    /// Like [`render`](Self::render), given just the text of the line the error is on.
+    pub fn render_line(&self, text: &str) -> String {
+        let mut out = format!("error: expected {}, found {}\n", self.expected, self.found);
+
+        let file = self
+            .file
+            .as_ref()
+            .map(|f| f.display().to_string())
+            .unwrap_or("<input>".to_string());
+        let day = self.day.map(|d| format!(" (day {d})")).unwrap_or_default();
+
+        let Some(Position { line, column }) = self.position else {
+            out += &format!("  --> {file}{day}\n");
+            return out;
+        };
+
+        let width = text
+            .char_indices()
+            .map(|(i, _)| i)
+            .nth(column - 1)
+            .and_then(|o| text.get(o..o + self.len))
+            .map(|s| s.chars().count())
+            .unwrap_or_default()
+            .max(1);
+        let text = text.trim_end_matches('\r');
+        let gutter = " ".repeat(line.to_string().len());
+
+        out += &format!("{gutter}--> {file}:{line}:{column}{day}\n");
+        out += &format!("{gutter} |\n");
+        out += &format!("{line} | {text}\n");
+        out += &format!(
+            "{gutter} | {}{}\n",
+            " ".repeat(column - 1),
+            "^".repeat(width)
+        );
+
+        out
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file.display())?;
+        }
+        if let Some(Position { line, column }) = self.position {
+            write!(f, "{line}:{column}:")?;
+        }
+        if self.file.is_some() || self.position.is_some() {
+            write!(f, " ")?;
+        }
+
+        write!(f, "expected {}, found {}", self.expected, self.found)
+    }
+}
+
+impl Error for ParseError {}
+
+pub type ParseResult<T> = Result<T, ParseError>;
+
+/// Reject inputs every parser would choke on, with a friendlier error than the parser would give.
+pub fn check_input(input: &str) -> ParseResult<()> {
+    if input.trim().is_empty() {
+        let mut e = ParseError::at(input, "some input");
+        e.found = "an empty file".to_string();
+        return Err(e);
+    }
+
+    if let Some(idx) = input.find('\r') {
+        let mut e = ParseError::at(&input[idx..idx + 1], "\"\\n\" line ending");
+        e.found += " (is the file CRLF?)";
+        return Err(e);
+    }
+
+    if input.ends_with("\n\n") {
+        // Point at the first of the trailing blank lines.
+        let idx = input.trim_end_matches('\n').len() + 1;
+        let mut e = ParseError::at(&input[idx..], "end of input");
+        e.found = "a blank line".to_string();
+        return Err(e);
+    }
+
+    Ok(())
+}
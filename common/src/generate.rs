@@ -0,0 +1,50 @@
+use crate::{rng::Rng, Answer, DynSolution, Solution};
+
+/// Random inputs for a day's puzzle, for testing its solution on many more cases than the real
+/// input and the samples cover.
+///
+/// The references are slow but obviously correct ways to get the answers, for checking the real
+/// solution against on small inputs.
+pub trait Generate: Solution {
+    /// A valid input in the day's format. Bigger sizes give bigger inputs.
+    fn generate(rng: &mut Rng, size: usize) -> String;
+
+    /// Part 1's answer, worked out the slow way. `None` if there's no reference for this part, or
+    /// the input is too big for it.
+    fn reference1(_input: &str) -> Option<Self::Part1> {
+        None
+    }
+
+    /// Part 2's answer, worked out the slow way. `None` if there's no reference for this part, or
+    /// the input is too big for it.
+    fn reference2(_input: &str) -> Option<Self::Part2> {
+        None
+    }
+}
+
+/// Object-safe view of a [`Generate`], like [`DynSolution`] is of a [`Solution`].
+pub trait DynGenerate: DynSolution {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String;
+    /// The reference answer to `part`, if there is one and the input has an answer.
+    fn reference(&self, input: &str, part: u8) -> Option<String>;
+}
+
+impl<G> DynGenerate for G
+where
+    G: Generate + Sync,
+    G::Model: 'static,
+    G::Part1: 'static,
+    G::Part2: 'static,
+{
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        <G as Generate>::generate(rng, size)
+    }
+
+    fn reference(&self, input: &str, part: u8) -> Option<String> {
+        match part {
+            1 => G::reference1(input)?.answer().ok(),
+            2 => G::reference2(input)?.answer().ok(),
+            _ => panic!("Invalid part {part}"),
+        }
+    }
+}
@@ -0,0 +1,16 @@
+pub mod error;
+pub mod generate;
+pub mod options;
+pub mod parse;
+pub mod rng;
+pub mod scan;
+pub mod solution;
+pub mod stream;
+
+pub use error::{ParseError, ParseResult};
+pub use generate::{DynGenerate, Generate};
+pub use options::{Configure, Configured, Options};
+pub use rng::Rng;
+pub use scan::{DynScan, Scan};
+pub use solution::{Answer, DynSolution, Solution, Unsolved};
+pub use stream::{DynStream, Stream, StreamError};
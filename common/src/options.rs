@@ -0,0 +1,81 @@
+use std::{collections::BTreeMap, str::FromStr, sync::Arc};
+
+use crate::{DynScan, DynSolution, DynStream};
+
+/// Settings for a day beyond its input, like which words d01 reads as digits. Each is a
+/// `key=value` pair, from the command line or the runner's options file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Options(BTreeMap<String, String>);
+
+impl Options {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set(&mut self, key: &str, value: &str) {
+        self.0.insert(key.to_string(), value.to_string());
+    }
+
+    /// Every option in `other`, replacing any already set.
+    pub fn extend(&mut self, other: &Options) {
+        self.0.extend(other.0.clone());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).map(|v| v.as_str())
+    }
+
+    /// The option `key` converted to a `T`, if it's set.
+    pub fn parse<T: FromStr>(&self, key: &str) -> Result<Option<T>, String> {
+        self.get(key)
+            .map(|v| v.parse().map_err(|_| format!("Bad value for {key}: {v:?}")))
+            .transpose()
+    }
+
+    /// Fail on any option that isn't one of `known`, since it's probably a typo.
+    pub fn check(&self, known: &[&str]) -> Result<(), String> {
+        match self.0.keys().find(|k| !known.contains(&k.as_str())) {
+            Some(k) => Err(format!(
+                "Unknown option {k:?}, expected one of {}",
+                known.join(", ")
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Parses one `key=value` option.
+impl FromStr for Options {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (key, value) = s
+            .split_once('=')
+            .ok_or_else(|| format!("Expected KEY=VALUE, found {s:?}"))?;
+
+        let mut options = Self::new();
+        options.set(key.trim(), value.trim());
+        Ok(options)
+    }
+}
+
+/// A day that takes [`Options`].
+///
+/// Options don't change the day itself: configuring it gives another solution with them applied
+/// to everything it does, parsing, both parts and streaming, which the runner runs instead.
+pub trait Configure: Sync {
+    /// Check `options` and apply them to a copy of the day. Options that aren't set keep their
+    /// defaults.
+    fn configure(&self, options: &Options) -> Result<Configured, String>;
+}
+
+/// A day with its options applied, and the other ways it has of reading its input.
+pub struct Configured {
+    pub solution: Arc<dyn DynSolution>,
+    pub stream: Option<Arc<dyn DynStream>>,
+    pub scan: Option<Arc<dyn DynScan>>,
+}
@@ -0,0 +1,463 @@
+//! Small parser combinators, shared so every day parses the same way and reports errors the same
+//! way.
+//!
+//! A parser takes the input, and returns what it parsed along with the rest of the input. Nothing
+//! is copied: everything parsed as text is a slice of the input, and so is every error, so the
+//! errors can be located in the input (see [`ParseError::locate`]).
+
+use std::str::FromStr;
+
+use crate::{ParseError, ParseResult};
+
+/// What a parser parsed, and the input it left.
+pub type Parsed<'a, T> = ParseResult<(T, &'a str)>;
+
+/// Anything that parses a `T` from the start of its input. Implemented for functions and closures
+/// of the right shape, so a parser can be written as either.
+pub trait Parser<'a, T> {
+    fn parse(&mut self, s: &'a str) -> Parsed<'a, T>;
+
+    /// Parse the whole of `s`, failing if anything is left.
+    fn all(&mut self, s: &'a str) -> ParseResult<T> {
+        let (t, rest) = self.parse(s)?;
+        end(rest)?;
+
+        Ok(t)
+    }
+
+    fn map<U>(mut self, mut f: impl FnMut(T) -> U) -> impl Parser<'a, U>
+    where
+        Self: Sized,
+    {
+        move |s: &'a str| self.parse(s).map(|(t, rest)| (f(t), rest))
+    }
+
+    /// Like [`map`](Self::map), for conversions that can fail. The error points at everything
+    /// this parser consumed.
+    fn try_map<U>(
+        mut self,
+        mut f: impl FnMut(T) -> Option<U>,
+        expected: &'static str,
+    ) -> impl Parser<'a, U>
+    where
+        Self: Sized,
+    {
+        move |s: &'a str| {
+            let (t, rest) = self.parse(s)?;
+            let u = f(t).ok_or_else(|| ParseError::at(&s[..s.len() - rest.len()], expected))?;
+
+            Ok((u, rest))
+        }
+    }
+}
+
+impl<'a, T, F> Parser<'a, T> for F
+where
+    F: FnMut(&'a str) -> Parsed<'a, T>,
+{
+    fn parse(&mut self, s: &'a str) -> Parsed<'a, T> {
+        self(s)
+    }
+}
+
+/// Succeeds only on empty input.
+pub fn end(s: &str) -> ParseResult<()> {
+    if s.is_empty() {
+        Ok(())
+    } else {
+        Err(ParseError::at(s, "end of line"))
+    }
+}
+
+// The longest run of characters at the start of `s` matching `f`, and the rest.
+fn split_while(s: &str, f: impl Fn(char) -> bool) -> (&str, &str) {
+    let len = s.find(|c| !f(c)).unwrap_or(s.len());
+    s.split_at(len)
+}
+
+/// Exactly `t`.
+pub fn tag<'a>(t: &'static str) -> impl Parser<'a, &'a str> {
+    move |s: &'a str| match s.strip_prefix(t) {
+        Some(rest) => Ok((&s[..t.len()], rest)),
+        None => Err(ParseError::at(s, format!("{t:?}"))),
+    }
+}
+
+/// Any number of spaces, including none.
+pub fn space0(s: &str) -> Parsed<'_, ()> {
+    Ok(((), s.trim_start_matches(' ')))
+}
+
+/// At least one space.
+pub fn space1(s: &str) -> Parsed<'_, ()> {
+    match s.strip_prefix(' ') {
+        Some(rest) => space0(rest),
+        None => Err(ParseError::at(s, "a space")),
+    }
+}
+
+/// A non-empty run of characters matching `f`.
+pub fn take_while1<'a>(
+    f: impl Fn(char) -> bool,
+    expected: &'static str,
+) -> impl Parser<'a, &'a str> {
+    move |s: &'a str| match split_while(s, &f) {
+        ("", _) => Err(ParseError::at(s, expected)),
+        run => Ok(run),
+    }
+}
+
+/// A name made of letters and digits.
+pub fn word(s: &str) -> Parsed<'_, &str> {
+    take_while1(|c| c.is_ascii_alphanumeric(), "a name").parse(s)
+}
+
+/// A number without a sign.
+pub fn unsigned<T: FromStr>(s: &str) -> Parsed<'_, T> {
+    let (digits, rest) = split_while(s, |c| c.is_ascii_digit());
+    if digits.is_empty() {
+        return Err(ParseError::at(s, "a number"));
+    }
+
+    // Only fails if it's too big for `T`.
+    let n = digits
+        .parse()
+        .map_err(|_| ParseError::at(digits, "a smaller number"))?;
+
+    Ok((n, rest))
+}
+
+/// A number, negative if it starts with '-'.
+pub fn signed<T: FromStr>(s: &str) -> Parsed<'_, T> {
+    let sign = usize::from(s.starts_with('-'));
+    let (digits, rest) = split_while(&s[sign..], |c| c.is_ascii_digit());
+    if digits.is_empty() {
+        return Err(ParseError::at(s, "a number"));
+    }
+
+    let number = &s[..sign + digits.len()];
+    let n = number
+        .parse()
+        .map_err(|_| ParseError::at(number, "a smaller number"))?;
+
+    Ok((n, rest))
+}
+
+/// A run of characters each converted with `f`, up to a space or the end of the line. `expected`
+/// describes the characters `f` accepts, for the error when it returns `None`.
+pub fn symbols<'a, T>(
+    mut f: impl FnMut(char) -> Option<T>,
+    expected: &'static str,
+) -> impl Parser<'a, Vec<T>> {
+    move |s: &'a str| {
+        let (run, rest) = split_while(s, |c| c != ' ' && c != '\n');
+        if run.is_empty() {
+            return Err(ParseError::at(s, expected));
+        }
+
+        let symbols = run
+            .char_indices()
+            .map(|(idx, c)| {
+                f(c).ok_or_else(|| ParseError::at(&run[idx..idx + c.len_utf8()], expected))
+            })
+            .collect::<ParseResult<_>>()?;
+
+        Ok((symbols, rest))
+    }
+}
+
+/// `p` if it's there. `None` if `p` fails right at the start, but a `p` that fails part way
+/// through is an error.
+pub fn opt<'a, T>(mut p: impl Parser<'a, T>) -> impl Parser<'a, Option<T>> {
+    move |s: &'a str| match p.parse(s) {
+        Ok((t, rest)) => Ok((Some(t), rest)),
+        Err(e) if e.is_at(s) => Ok((None, s)),
+        Err(e) => Err(e),
+    }
+}
+
+/// One or more `item`s with a `sep` between each. The list ends at a `sep` not followed by an
+/// item, but an item that fails part way through is an error.
+pub fn list<'a, T, S>(
+    mut item: impl Parser<'a, T>,
+    mut sep: impl Parser<'a, S>,
+) -> impl Parser<'a, Vec<T>> {
+    move |s: &'a str| {
+        let (first, mut rest) = item.parse(s)?;
+        let mut items = vec![first];
+
+        while let Ok((_, after)) = sep.parse(rest) {
+            match item.parse(after) {
+                Ok((next, after)) => {
+                    items.push(next);
+                    rest = after;
+                }
+                // Nothing like an item after the separator, so it belongs to whatever comes next,
+                // like the space before the '|' in "41 48 | 83 86".
+                Err(e) if e.is_at(after) => break,
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok((items, rest))
+    }
+}
+
+/// `label`, then any spaces, then `p`, e.g. "Time:  7  15  30".
+pub fn labelled<'a, T>(label: &'static str, mut p: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    let mut label = tag(label);
+
+    move |s: &'a str| {
+        let (_, rest) = label.parse(s)?;
+        let (_, rest) = space0(rest)?;
+        p.parse(rest)
+    }
+}
+
+/// One line parsed whole with `p`. The newline is skipped.
+pub fn line<'a, T>(mut p: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    move |s: &'a str| {
+        let (line, rest) = s.split_once('\n').unwrap_or((s, &s[s.len()..]));
+        Ok((p.all(line)?, rest))
+    }
+}
+
+/// Every line of the input, each parsed whole with `p`.
+pub fn lines<'a, T>(mut p: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    move |s: &'a str| {
+        let lines = s.lines().map(|l| p.all(l)).collect::<ParseResult<_>>()?;
+        Ok((lines, &s[s.len()..]))
+    }
+}
+
+/// The lines up to the next blank line or the end of the input, parsed whole with `p`. The blank
+/// line is skipped.
+pub fn block<'a, T>(mut p: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    move |s: &'a str| {
+        let (block, rest) = match s.find("\n\n") {
+            Some(idx) => (&s[..idx], &s[idx + 2..]),
+            None => (s.strip_suffix('\n').unwrap_or(s), &s[s.len()..]),
+        };
+
+        Ok((p.all(block)?, rest))
+    }
+}
+
+/// Every blank-line-separated block of the input, each parsed whole with `p`.
+pub fn blocks<'a, T>(p: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    let mut block = block(p);
+
+    move |mut s: &'a str| {
+        let mut blocks = Vec::new();
+        while !s.is_empty() {
+            let (b, rest) = block.parse(s)?;
+            blocks.push(b);
+            s = rest;
+        }
+
+        Ok((blocks, s))
+    }
+}
+
+/// A header line parsed with `header`, then the rest of the lines each parsed with `body`, e.g.
+/// "seed-to-soil map:" and its ranges.
+pub fn section<'a, H, T>(
+    header: impl Parser<'a, H>,
+    body: impl Parser<'a, T>,
+) -> impl Parser<'a, (H, Vec<T>)> {
+    let mut header = line(header);
+    let mut lines = lines(body);
+
+    move |s: &'a str| {
+        let (h, rest) = header.parse(s)?;
+        let (body, rest) = lines.parse(rest)?;
+
+        Ok(((h, body), rest))
+    }
+}
+
+/// The cells of a character grid, a row at a time.
+pub struct Cells<T> {
+    pub width: usize,
+    pub height: usize,
+    pub cells: Vec<T>,
+}
+
+/// A grid with one row per line, converting each character with `f`. `expected` describes the
+/// characters `f` accepts, for the error when it returns `None`. Every row must be as wide as the
+/// first.
+pub fn cells<'a, T>(
+    mut f: impl FnMut(char) -> Option<T>,
+    expected: &'static str,
+) -> impl Parser<'a, Cells<T>> {
+    move |s: &'a str| {
+        let mut width = 0;
+        let mut height = 0;
+        let mut cells: Vec<T> = Vec::new();
+
+        for l in s.lines() {
+            for (idx, c) in l.char_indices() {
+                let cell =
+                    f(c).ok_or_else(|| ParseError::at(&l[idx..idx + c.len_utf8()], expected))?;
+                cells.push(cell);
+            }
+
+            let row_width = l.chars().count();
+            if height == 0 {
+                width = row_width;
+            }
+            if row_width != width {
+                let at = l
+                    .char_indices()
+                    .nth(width)
+                    .map(|(i, _)| i)
+                    .unwrap_or(l.len());
+                return Err(ParseError::at(
+                    &l[at..],
+                    format!("a row {width} tiles wide"),
+                ));
+            }
+
+            height += 1;
+        }
+
+        Ok((
+            Cells {
+                width,
+                height,
+                cells,
+            },
+            &s[s.len()..],
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{cells, labelled, list, opt, space1, tag, unsigned, Cells, Parsed, Parser};
+    use crate::ParseResult;
+
+    // Where in `input` the parse failed, what was expected there, and what was found.
+    fn error<T>(input: &str, result: ParseResult<T>) -> (usize, usize, String, String) {
+        let e = result.err().expect("parse should fail").locate(input);
+        let position = e.position.unwrap();
+
+        (position.line, position.column, e.expected, e.found)
+    }
+
+    // "a=1", failing part way through on anything but a number after the "a=".
+    fn assignment(s: &str) -> Parsed<'_, u32> {
+        labelled("a=", unsigned).parse(s)
+    }
+
+    #[test]
+    fn list_items() {
+        let mut numbers = list(unsigned::<u32>, tag(", "));
+
+        assert_eq!(numbers.parse("1, 2, 3"), Ok((vec![1, 2, 3], "")));
+        assert_eq!(numbers.parse("1; 2"), Ok((vec![1], "; 2")));
+
+        let input = "x, 1";
+        assert_eq!(
+            error(input, numbers.parse(input)),
+            (1, 1, "a number".to_string(), "\"x, 1\"".to_string())
+        );
+    }
+
+    #[test]
+    fn list_leaves_a_trailing_separator() {
+        // The space before the '|' isn't followed by a number, so it's left for what comes next.
+        let mut numbers = list(unsigned::<u32>, space1);
+        assert_eq!(numbers.parse("41 48 | 83"), Ok((vec![41, 48], " | 83")));
+
+        let mut numbers = list(unsigned::<u32>, tag(","));
+        assert_eq!(numbers.parse("1,2,"), Ok((vec![1, 2], ",")));
+    }
+
+    #[test]
+    fn list_fails_part_way_through_an_item() {
+        let mut assignments = list(assignment, tag(","));
+        assert_eq!(assignments.parse("a=1,a=2"), Ok((vec![1, 2], "")));
+        assert_eq!(assignments.parse("a=1,b=2"), Ok((vec![1], ",b=2")));
+
+        // The second item fails after its "a=", so the list can't stop before it.
+        let input = "a=1,a=x";
+        assert_eq!(
+            error(input, assignments.parse(input)),
+            (1, 7, "a number".to_string(), "\"x\"".to_string())
+        );
+    }
+
+    #[test]
+    fn opt_backtracks_only_at_the_start() {
+        let mut maybe = opt(assignment);
+
+        assert_eq!(maybe.parse("a=5 rest"), Ok((Some(5), " rest")));
+        assert_eq!(maybe.parse("b=5"), Ok((None, "b=5")));
+        assert_eq!(maybe.parse(""), Ok((None, "")));
+
+        let input = "a=?";
+        assert_eq!(
+            error(input, maybe.parse(input)),
+            (1, 3, "a number".to_string(), "\"?\"".to_string())
+        );
+    }
+
+    #[test]
+    fn all_needs_the_whole_input() {
+        let input = "12 ";
+        assert_eq!(
+            error(input, unsigned::<u32>.all(input)),
+            (1, 3, "end of line".to_string(), "\" \"".to_string())
+        );
+
+        let input = "300";
+        assert_eq!(
+            error(input, unsigned::<u8>.all(input)),
+            (1, 1, "a smaller number".to_string(), "\"300\"".to_string())
+        );
+    }
+
+    fn digits(input: &str) -> ParseResult<Cells<u32>> {
+        cells(|c| c.to_digit(10), "a digit").all(input)
+    }
+
+    #[test]
+    fn cells_grid() {
+        let grid = digits("123\n456\n").unwrap();
+
+        assert_eq!((grid.width, grid.height), (3, 2));
+        assert_eq!(grid.cells, [1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn cells_row_width() {
+        // Too wide: the error points at the first tile past the width.
+        let input = "12\n345\n";
+        assert_eq!(
+            error(input, digits(input)),
+            (2, 3, "a row 2 tiles wide".to_string(), "\"5\"".to_string())
+        );
+
+        // Too narrow: the error points at where the row ends.
+        let input = "123\n45\n678\n";
+        assert_eq!(
+            error(input, digits(input)),
+            (
+                2,
+                3,
+                "a row 3 tiles wide".to_string(),
+                "end of line".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn cells_bad_tile() {
+        let input = "12\n3x\n";
+        assert_eq!(
+            error(input, digits(input)),
+            (2, 2, "a digit".to_string(), "\"x\"".to_string())
+        );
+    }
+}
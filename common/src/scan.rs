@@ -0,0 +1,46 @@
+use crate::{
+    error::{check_input, ParseResult},
+    Answer, DynSolution, Solution,
+};
+
+/// A [`Solution`] that can also work out its answers straight from the whole input, without
+/// building a model, e.g. by splitting it between threads. Like a [`Stream`](crate::Stream), it's
+/// another way to the same answers, for inputs too big to parse.
+pub trait Scan: Solution {
+    /// Whether to scan rather than parse, usually because the day's options asked for it.
+    fn scanning(&self) -> bool;
+
+    /// Part 1's answer. Errors in the input are part of the answer, as for a part that can fail.
+    fn scan1(&self, input: &str) -> Self::Part1;
+    fn scan2(&self, input: &str) -> Self::Part2;
+}
+
+/// Object-safe view of a [`Scan`], like [`DynSolution`] is of a [`Solution`].
+pub trait DynScan: DynSolution {
+    fn scanning(&self) -> bool;
+
+    /// The answer to `part`, with any error located in `input`.
+    fn scan(&self, input: &str, part: u8) -> ParseResult<String>;
+}
+
+impl<S> DynScan for S
+where
+    S: Scan + Sync,
+    S::Model: 'static,
+    S::Part1: 'static,
+    S::Part2: 'static,
+{
+    fn scanning(&self) -> bool {
+        Scan::scanning(self)
+    }
+
+    fn scan(&self, input: &str, part: u8) -> ParseResult<String> {
+        check_input(input)
+            .and_then(|_| match part {
+                1 => self.scan1(input).answer(),
+                2 => self.scan2(input).answer(),
+                _ => panic!("Invalid part {part}"),
+            })
+            .map_err(|e| e.locate(input))
+    }
+}
@@ -0,0 +1,124 @@
+use std::{
+    any::{Any, TypeId},
+    fmt::Display,
+};
+
+use crate::error::{check_input, ParseError, ParseResult};
+
+/// A single day's puzzle.
+///
+/// The input is parsed once into a `Model`, which both parts then solve from. Malformed input is
+/// reported as a [`ParseError`] rather than a panic.
+pub trait Solution {
+    type Model;
+    type Part1: Answer;
+    type Part2: Answer;
+
+    fn parse(&self, input: &str) -> ParseResult<Self::Model>;
+    fn part1(&self, model: &Self::Model) -> Self::Part1;
+    fn part2(&self, model: &Self::Model) -> Self::Part2;
+
+    /// Anything to show about the model besides the answers, like a table its options asked for.
+    /// The runner prints it once, whichever parts run.
+    fn report(&self, _model: &Self::Model) -> Option<String> {
+        None
+    }
+}
+
+/// The answer to a part that hasn't been solved yet. The runner leaves such parts out rather than
+/// report this as an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unsolved")
+    }
+}
+
+/// What a part answers with. A part that can't answer every input the model allows, like part 2
+/// of a sample only meant for part 1, answers with a [`ParseResult`] whose error says what's
+/// missing, located by the model since the input is gone by then.
+pub trait Answer {
+    fn answer(self) -> ParseResult<String>;
+}
+
+macro_rules! display_answer {
+    ($($t:ty),*) => {
+        $(
+            impl Answer for $t {
+                fn answer(self) -> ParseResult<String> {
+                    Ok(self.to_string())
+                }
+            }
+        )*
+    };
+}
+
+display_answer!(i32, i64, u32, u64, usize, String, Unsolved);
+
+impl<T: Answer> Answer for ParseResult<T> {
+    fn answer(self) -> ParseResult<String> {
+        self?.answer()
+    }
+}
+
+/// Object-safe view of a [`Solution`], so the runner can hold every day in one table.
+///
+/// Implemented for every `Solution`; the model is type-erased and answers are rendered to strings.
+pub trait DynSolution: Sync {
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+    fn part1(&self, model: &dyn Any) -> ParseResult<String>;
+    fn part2(&self, model: &dyn Any) -> ParseResult<String>;
+    /// Whether `part` has a solution, rather than answering [`Unsolved`].
+    fn solved(&self, part: u8) -> bool;
+    fn report(&self, model: &dyn Any) -> Option<String>;
+
+    fn part(&self, model: &dyn Any, part: u8) -> ParseResult<String> {
+        match part {
+            1 => self.part1(model),
+            2 => self.part2(model),
+            _ => panic!("Invalid part {part}"),
+        }
+    }
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution + Sync,
+    S::Model: 'static,
+    S::Part1: 'static,
+    S::Part2: 'static,
+{
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        check_input(input)
+            .and_then(|_| Solution::parse(self, input))
+            .map(|m| Box::new(m) as Box<dyn Any>)
+            .map_err(|e| e.locate(input))
+    }
+
+    fn part1(&self, model: &dyn Any) -> ParseResult<String> {
+        let model = model.downcast_ref().expect("Model from another day!");
+        Solution::part1(self, model).answer()
+    }
+
+    fn part2(&self, model: &dyn Any) -> ParseResult<String> {
+        let model = model.downcast_ref().expect("Model from another day!");
+        Solution::part2(self, model).answer()
+    }
+
+    fn report(&self, model: &dyn Any) -> Option<String> {
+        let model = model.downcast_ref().expect("Model from another day!");
+        Solution::report(self, model)
+    }
+
+    fn solved(&self, part: u8) -> bool {
+        let answer = match part {
+            1 => TypeId::of::<S::Part1>(),
+            2 => TypeId::of::<S::Part2>(),
+            _ => panic!("Invalid part {part}"),
+        };
+
+        answer != TypeId::of::<Unsolved>()
+    }
+}
@@ -0,0 +1,201 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    io::{self, BufRead},
+};
+
+use crate::{error::ParseError, Answer, DynSolution, ParseResult, Solution};
+
+/// A [`Solution`] whose answers can be added up a line at a time, so the input never has to be
+/// held in memory whole. The day still parses into a model as usual; this is another way to get
+/// the same answers for inputs too big for that.
+///
+/// Like the model's parts, each part keeps its own totals, so a part that can't handle an input
+/// isn't run on it unless asked for.
+pub trait Stream: Solution {
+    /// Part 1's running totals, up to the lines seen so far.
+    type Totals1;
+    /// Part 2's running totals, up to the lines seen so far.
+    type Totals2;
+
+    /// Part 1's totals before any lines.
+    fn totals1(&self) -> Self::Totals1;
+    /// Part 2's totals before any lines.
+    fn totals2(&self) -> Self::Totals2;
+
+    /// The totals for the parts in `parts` before any lines, for days that need to know which
+    /// parts are running, like one that prints something once whichever of them does.
+    fn totals(&self, parts: &[u8]) -> (Option<Self::Totals1>, Option<Self::Totals2>) {
+        (
+            parts.contains(&1).then(|| self.totals1()),
+            parts.contains(&2).then(|| self.totals2()),
+        )
+    }
+
+    /// Add one line, without its newline, to part 1's totals.
+    fn line1(&self, totals: &mut Self::Totals1, line: &str) -> ParseResult<()>;
+    /// Add one line, without its newline, to part 2's totals.
+    fn line2(&self, totals: &mut Self::Totals2, line: &str) -> ParseResult<()>;
+
+    fn answer1(&self, totals: Self::Totals1) -> Self::Part1;
+    fn answer2(&self, totals: Self::Totals2) -> Self::Part2;
+}
+
+/// Why a streamed input gave no answers.
+#[derive(Debug)]
+pub enum StreamError {
+    Read(io::Error),
+    /// The line is kept for rendering the error, since the rest of the input is gone.
+    Parse {
+        error: Box<ParseError>,
+        line: String,
+    },
+}
+
+impl Display for StreamError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Read(e) => write!(f, "{e}"),
+            Self::Parse { error, .. } => write!(f, "{error}"),
+        }
+    }
+}
+
+impl Error for StreamError {}
+
+/// Object-safe view of a [`Stream`], like [`DynSolution`] is of a [`Solution`].
+pub trait DynStream: DynSolution {
+    /// The answers to `parts`, in the same order, reading `input` to the end. A line one part
+    /// can't read only stops that part; errors in the input as a whole stop them all.
+    fn stream(
+        &self,
+        input: &mut dyn BufRead,
+        parts: &[u8],
+    ) -> Result<Vec<Result<String, StreamError>>, StreamError>;
+}
+
+fn fail(error: ParseError, number: usize, line: &str) -> StreamError {
+    StreamError::Parse {
+        error: Box::new(error.on_line(number, line)),
+        line: line.to_string(),
+    }
+}
+
+// The totals for the parts being run, or why a part stopped.
+struct Totals<'a, S: Stream> {
+    stream: &'a S,
+    part1: Option<Result<S::Totals1, StreamError>>,
+    part2: Option<Result<S::Totals2, StreamError>>,
+}
+
+impl<S: Stream> Totals<'_, S> {
+    // Add the `number`th line to the parts still going.
+    fn line(&mut self, number: usize, line: &str) {
+        if let Some(Ok(totals)) = &mut self.part1 {
+            if let Err(e) = self.stream.line1(totals, line) {
+                self.part1 = Some(Err(fail(e, number, line)));
+            }
+        }
+        if let Some(Ok(totals)) = &mut self.part2 {
+            if let Err(e) = self.stream.line2(totals, line) {
+                self.part2 = Some(Err(fail(e, number, line)));
+            }
+        }
+    }
+}
+
+// An answer that turned out to be an error, with no line to show for it.
+fn answer(answer: impl Answer) -> Result<String, StreamError> {
+    answer.answer().map_err(|e| StreamError::Parse {
+        error: Box::new(e),
+        line: String::new(),
+    })
+}
+
+impl<S> DynStream for S
+where
+    S: Stream + Sync,
+    S::Model: 'static,
+    S::Part1: 'static,
+    S::Part2: 'static,
+{
+    fn stream(
+        &self,
+        input: &mut dyn BufRead,
+        parts: &[u8],
+    ) -> Result<Vec<Result<String, StreamError>>, StreamError> {
+        let (part1, part2) = self.totals(parts);
+        let mut totals = Totals {
+            stream: self,
+            part1: part1.map(Ok),
+            part2: part2.map(Ok),
+        };
+        let mut buffer = String::new();
+        let mut number = 0;
+        let mut blank = None;
+        let mut empty = true;
+
+        loop {
+            buffer.clear();
+            if input.read_line(&mut buffer).map_err(StreamError::Read)? == 0 {
+                break;
+            }
+            number += 1;
+
+            // The same checks `check_input` makes on a whole input.
+            let line = buffer.strip_suffix('\n').unwrap_or(&buffer);
+            if let Some(idx) = line.find('\r') {
+                let mut e = ParseError::at(&line[idx..idx + 1], "\"\\n\" line ending");
+                e.found += " (is the file CRLF?)";
+                return Err(fail(e, number, line));
+            }
+
+            // A blank line is only an error if it's trailing, which we don't know until the end.
+            if line.is_empty() {
+                blank.get_or_insert(number);
+                continue;
+            }
+            if let Some(first) = blank.take() {
+                for n in first..number {
+                    totals.line(n, "");
+                }
+            }
+
+            totals.line(number, line);
+            empty = false;
+        }
+
+        if empty {
+            let mut e = ParseError::at("", "some input");
+            e.found = "an empty file".to_string();
+            return Err(StreamError::Parse {
+                error: Box::new(e),
+                line: String::new(),
+            });
+        }
+        if let Some(first) = blank {
+            let mut e = ParseError::at("", "end of input");
+            e.found = "a blank line".to_string();
+            return Err(fail(e, first, ""));
+        }
+
+        let mut part1 = totals
+            .part1
+            .map(|t| t.and_then(|t| answer(self.answer1(t))));
+        let mut part2 = totals
+            .part2
+            .map(|t| t.and_then(|t| answer(self.answer2(t))));
+
+        Ok(parts
+            .iter()
+            .map(|&part| {
+                match part {
+                    1 => part1.take(),
+                    2 => part2.take(),
+                    _ => None,
+                }
+                .unwrap_or_else(|| panic!("Invalid part {part}"))
+            })
+            .collect())
+    }
+}
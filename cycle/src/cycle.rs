@@ -0,0 +1,136 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// A sequence of states that ends up going round in a loop, made by repeatedly stepping from a
+/// start state.
+///
+/// After `prefix` steps the sequence reaches a state it comes back to every `period` steps after
+/// that, so the state after any number of steps can be found without taking them all.
+pub struct Cycle<S> {
+    pub prefix: usize,
+    pub period: usize,
+    // Every state up to the first repeat, starting with the start state.
+    states: Vec<S>,
+}
+
+impl<S: Clone + Eq + Hash> Cycle<S> {
+    /// Step from `start` until a state repeats. Never returns if no state ever does.
+    pub fn find(start: S, step: impl FnMut(&S) -> S) -> Self {
+        Self::find_by(start, step, Clone::clone)
+    }
+
+    /// Step from `start` until the part of the state picked out by `key` repeats, for states
+    /// where only that part decides what comes next. The cycle is of the keys, so `nth` projects
+    /// the state at any step onto its key. Never returns if no key ever repeats.
+    pub fn find_by<T>(
+        start: T,
+        mut step: impl FnMut(&T) -> T,
+        mut key: impl FnMut(&T) -> S,
+    ) -> Self {
+        let first = key(&start);
+        let mut seen = HashMap::from([(first.clone(), 0)]);
+        let mut states = vec![first];
+        let mut state = start;
+
+        loop {
+            state = step(&state);
+            let next = key(&state);
+
+            if let Some(&prefix) = seen.get(&next) {
+                let period = states.len() - prefix;
+                return Self {
+                    prefix,
+                    period,
+                    states,
+                };
+            }
+
+            seen.insert(next.clone(), states.len());
+            states.push(next);
+        }
+    }
+}
+
+impl<S> Cycle<S> {
+    /// The first step to reach the same state as step `n`.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.period
+        }
+    }
+
+    /// The state, or its key, after `n` steps.
+    pub fn nth(&self, n: usize) -> &S {
+        &self.states[self.reduce(n)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Cycle;
+
+    #[test]
+    fn prefix_and_period() {
+        // 0, 1, 2, 3, 4, 2, 3, 4, ...
+        let cycle = Cycle::find(0, |&s| if s == 4 { 2 } else { s + 1 });
+        assert_eq!((cycle.prefix, cycle.period), (2, 3));
+
+        // Before the loop, steps are their own.
+        assert_eq!(cycle.reduce(0), 0);
+        assert_eq!(cycle.reduce(1), 1);
+        // The loop starts at the prefix.
+        assert_eq!(cycle.reduce(2), 2);
+        assert_eq!(cycle.reduce(4), 4);
+        assert_eq!(cycle.reduce(5), 2);
+        assert_eq!(cycle.reduce(1_000_000_000), 4);
+
+        let states: Vec<i32> = (0..10).map(|n| *cycle.nth(n)).collect();
+        assert_eq!(states, [0, 1, 2, 3, 4, 2, 3, 4, 2, 3]);
+    }
+
+    #[test]
+    fn period_one() {
+        // 5, 4, 3, 3, 3, ...
+        let cycle = Cycle::find(5, |&s: &i32| (s - 1).max(3));
+        assert_eq!((cycle.prefix, cycle.period), (2, 1));
+
+        assert_eq!(*cycle.nth(1), 4);
+        assert_eq!(*cycle.nth(2), 3);
+        assert_eq!(*cycle.nth(usize::MAX), 3);
+    }
+
+    #[test]
+    fn no_prefix() {
+        // The start state is part of the loop: 0, 1, 2, 0, ...
+        let cycle = Cycle::find(0, |&s| (s + 1) % 3);
+        assert_eq!((cycle.prefix, cycle.period), (0, 3));
+        assert_eq!(*cycle.nth(7), 1);
+
+        // A state that steps to itself.
+        let cycle = Cycle::find('x', |&s| s);
+        assert_eq!((cycle.prefix, cycle.period), (0, 1));
+        assert_eq!(*cycle.nth(0), 'x');
+        assert_eq!(*cycle.nth(100), 'x');
+    }
+
+    #[test]
+    fn projected() {
+        // The state counts up forever, but its key only goes 0, 1, 2, 3, 4, 0, ...
+        let cycle = Cycle::find_by(0_u64, |&s| s + 1, |&s| s % 5);
+        assert_eq!((cycle.prefix, cycle.period), (0, 5));
+        assert_eq!(*cycle.nth(3), 3);
+        assert_eq!(*cycle.nth(1_000_000_003), 3);
+
+        // The key can settle into a loop after a prefix: 9, 8, 7, 6, 5, 6, 5, ...
+        let cycle = Cycle::find_by(
+            (0_u32, 9_i32),
+            |&(n, k)| (n + 1, if k > 5 { k - 1 } else { 11 - k }),
+            |&(_, k)| k,
+        );
+        assert_eq!((cycle.prefix, cycle.period), (3, 2));
+        assert_eq!(*cycle.nth(4), 5);
+        assert_eq!(*cycle.nth(1001), 6);
+        assert_eq!(*cycle.nth(1000), 5);
+    }
+}
@@ -0,0 +1,3 @@
+mod cycle;
+
+pub use cycle::Cycle;
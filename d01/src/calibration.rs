@@ -0,0 +1,287 @@
+use std::{cmp::Reverse, fmt::Display, str::FromStr, time::Instant};
+
+use aho_corasick::{AhoCorasick, Match};
+use common::{ParseError, ParseResult};
+use tracing::{info, warn};
+
+use crate::Settings;
+
+/// What to do with a line with no digits on it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Digitless {
+    Skip,
+    /// Count it as a calibration value of 0. Same sum as skipping, but counted as a line.
+    Zero,
+    #[default]
+    Error,
+}
+
+impl FromStr for Digitless {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "skip" => Ok(Self::Skip),
+            "zero" => Ok(Self::Zero),
+            "error" => Ok(Self::Error),
+            _ => Err(format!(
+                "Unknown digitless policy {s:?}, expected skip, zero or error"
+            )),
+        }
+    }
+}
+
+impl Display for Digitless {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Skip => write!(f, "skipped"),
+            Self::Zero => write!(f, "counted as 0"),
+            Self::Error => write!(f, "an error"),
+        }
+    }
+}
+
+// A running sum of one part's calibration values, with the matcher it finds digits with, and
+// counts of the lines worth knowing about.
+pub struct Calibration {
+    part: u8,
+    matcher: AhoCorasick,
+    // The digit of each of the matcher's patterns.
+    digits: Vec<u64>,
+    digitless: Digitless,
+    explain: bool,
+    sum: u64,
+    start: Instant,
+    lines: usize,
+    bytes: usize,
+    missing: usize,
+    overlapping: usize,
+}
+
+impl Calibration {
+    pub fn new(part: u8, settings: &Settings) -> Self {
+        let vocabulary = match part {
+            1 => &settings.part1,
+            _ => &settings.part2,
+        };
+
+        Self {
+            part,
+            matcher: AhoCorasick::new(vocabulary.patterns()).unwrap(),
+            digits: vocabulary.words().iter().map(|(_, d)| *d as u64).collect(),
+            digitless: settings.digitless,
+            explain: settings.explain,
+            sum: 0,
+            start: Instant::now(),
+            lines: 0,
+            bytes: 0,
+            missing: 0,
+            overlapping: 0,
+        }
+    }
+
+    // Add the first and last digits on the next line, as a two-digit number. Where words overlap,
+    // the first is the one starting first and the last the one ending last, the longer word
+    // winning a tie, so "viii" reads as 8 both ways rather than 5 or 1.
+    pub fn add(&mut self, line: &str) -> ParseResult<()> {
+        self.lines += 1;
+        self.bytes += line.len() + 1;
+        let matches: Vec<Match> = self.matcher.find_overlapping_iter(line).collect();
+
+        let first = matches.iter().min_by_key(|m| (m.start(), Reverse(m.len())));
+        let last = matches.iter().max_by_key(|m| (m.end(), m.len()));
+        let (Some(first), Some(last)) = (first, last) else {
+            return self.no_digits(line);
+        };
+
+        let value = 10 * self.digits[first.pattern()] + self.digits[last.pattern()];
+        self.sum += value;
+
+        let overlapping = needs_overlap(last, &matches);
+        if overlapping {
+            self.overlapping += 1;
+        }
+
+        if self.explain {
+            eprintln!(
+                "part {}, line {}: first {:?} at {:?}, last {:?} at {:?} -> {value}{}",
+                self.part,
+                self.lines,
+                &line[first.range()],
+                first.range(),
+                &line[last.range()],
+                last.range(),
+                if overlapping { " (overlapping)" } else { "" }
+            );
+        }
+
+        Ok(())
+    }
+
+    fn no_digits(&mut self, line: &str) -> ParseResult<()> {
+        if self.digitless == Digitless::Error {
+            return Err(
+                ParseError::at(line, format!("a digit (part {})", self.part))
+                    .on_line(self.lines, line),
+            );
+        }
+
+        self.missing += 1;
+        if self.explain {
+            eprintln!(
+                "part {}, line {}: no digits, {}",
+                self.part, self.lines, self.digitless
+            );
+        } else {
+            warn!(
+                part = self.part,
+                line = self.lines,
+                "no digits, {}",
+                self.digitless
+            );
+        }
+
+        Ok(())
+    }
+
+    /// The sum of the calibration values, with a summary of the lines in explain mode.
+    pub fn finish(self) -> u64 {
+        let elapsed = self.start.elapsed();
+        info!(
+            part = self.part,
+            "{} lines, {:.2} GB in {elapsed:.2?}, {:.2} GB/s",
+            self.lines,
+            self.bytes as f64 / 1e9,
+            self.bytes as f64 / 1e9 / elapsed.as_secs_f64()
+        );
+
+        if self.explain {
+            eprintln!(
+                "part {}: {} lines, {} relied on overlapping words, {} had no digits",
+                self.part, self.lines, self.overlapping, self.missing
+            );
+        }
+
+        self.sum
+    }
+}
+
+// Whether reading the line a word at a time, left to right and carrying on after each word found,
+// would miss the `last` one, like "two" in "eightwo".
+fn needs_overlap(last: &Match, matches: &[Match]) -> bool {
+    let mut from = 0;
+
+    loop {
+        let next = matches
+            .iter()
+            .filter(|m| m.start() >= from)
+            .min_by_key(|m| (m.start(), Reverse(m.len())));
+
+        match next {
+            Some(m) if m == last => return false,
+            Some(m) => from = m.end(),
+            None => return true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+    use crate::{Vocabulary, VOCABULARIES};
+
+    fn calibrate(part: u8, settings: &Settings, input: &str) -> (ParseResult<u64>, Calibration) {
+        let mut calibration = Calibration::new(part, settings);
+        let sum = input
+            .lines()
+            .try_for_each(|l| calibration.add(l))
+            .map(|_| calibration.sum);
+        (sum, calibration)
+    }
+
+    fn digitless(digitless: Digitless) -> Settings {
+        Settings {
+            digitless,
+            ..Settings::default()
+        }
+    }
+
+    const DIGITLESS: &str = "1abc2\nno digits\nthree3four\n";
+
+    #[test]
+    fn skip_digitless() {
+        let (sum, calibration) = calibrate(1, &digitless(Digitless::Skip), DIGITLESS);
+        assert_eq!(sum, Ok(12 + 33));
+        assert_eq!((calibration.lines, calibration.missing), (3, 1));
+    }
+
+    #[test]
+    fn zero_digitless() {
+        let (sum, calibration) = calibrate(1, &digitless(Digitless::Zero), DIGITLESS);
+        assert_eq!(sum, Ok(12 + 33));
+        assert_eq!((calibration.lines, calibration.missing), (3, 1));
+    }
+
+    #[test]
+    fn error_digitless() {
+        let (sum, _) = calibrate(1, &digitless(Digitless::Error), DIGITLESS);
+        let e = sum.unwrap_err();
+        assert_eq!(e.expected, "a digit (part 1)");
+        assert_eq!(e.found, "\"no digits\"");
+        let position = e.position.unwrap();
+        assert_eq!((position.line, position.column), (2, 1));
+
+        // Part 2 reads "three" and "four", so the line only has no digits for part 1.
+        let (sum, _) = calibrate(2, &Settings::default(), "1abc2\nthreefour\n");
+        assert_eq!(sum, Ok(12 + 34));
+
+        // An empty line is missing its digit at the start.
+        let (sum, _) = calibrate(2, &Settings::default(), "1abc2\n\n");
+        let e = sum.unwrap_err();
+        assert_eq!(e.expected, "a digit (part 2)");
+        assert_eq!(e.found, "end of line");
+        let position = e.position.unwrap();
+        assert_eq!((position.line, position.column), (2, 1));
+    }
+
+    #[test]
+    fn overlapping_words() {
+        let settings = Settings::default();
+
+        let (sum, calibration) = calibrate(2, &settings, "eightwo\n");
+        assert_eq!(sum, Ok(82));
+        assert_eq!(calibration.overlapping, 1);
+
+        let (sum, calibration) = calibrate(2, &settings, "oneight\n");
+        assert_eq!(sum, Ok(18));
+        assert_eq!(calibration.overlapping, 1);
+
+        // "two" and "one" overlap, but reading word by word still finds the last one.
+        let (sum, calibration) = calibrate(2, &settings, "twone3\n");
+        assert_eq!(sum, Ok(23));
+        assert_eq!(calibration.overlapping, 0);
+    }
+
+    #[test]
+    fn overlapping_roman() {
+        let roman = Vocabulary::named("roman", Path::new(VOCABULARIES)).unwrap();
+        let settings = Settings {
+            part2: roman,
+            ..Settings::default()
+        };
+
+        // The longest word wins wherever several start or end together.
+        for (line, value) in [
+            ("viii", 88),
+            ("iv", 44),
+            ("xixv", 95),
+            ("ivi", 46),
+            ("v", 55),
+        ] {
+            let (sum, _) = calibrate(2, &settings, line);
+            assert_eq!(sum, Ok(value), "{line}");
+        }
+    }
+}
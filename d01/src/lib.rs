@@ -0,0 +1,181 @@
+use std::{borrow::Cow, path::PathBuf, sync::Arc, thread};
+
+use calibration::Calibration;
+use common::{Configure, Configured, Options, ParseResult, Scan, Solution, Stream};
+
+pub use calibration::Digitless;
+pub use vocabulary::{Vocabulary, VOCABULARIES};
+
+mod calibration;
+mod scan;
+mod vocabulary;
+
+pub const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+// The vocabulary each part reads digits with: part 1 only counts digits, and part 2 spelled out
+// ones as well, unless the options say otherwise. With `explain`, each part prints how it read
+// every line to stderr, out of the way of the answers. With `threads`, the runner scans the whole
+// input split between that many threads instead of streaming it.
+#[derive(Clone)]
+struct Settings {
+    part1: Vocabulary,
+    part2: Vocabulary,
+    digitless: Digitless,
+    explain: bool,
+    threads: Option<usize>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            part1: Vocabulary::digits(),
+            part2: Vocabulary::english(),
+            digitless: Digitless::default(),
+            explain: false,
+            threads: None,
+        }
+    }
+}
+
+pub struct Totals1(Calibration);
+pub struct Totals2(Calibration);
+
+/// Day 1, with the settings from its options, or the puzzle's if it hasn't any.
+pub struct Day01(Option<Settings>);
+
+impl Day01 {
+    /// The day as the puzzle sets it.
+    pub const PUZZLE: Self = Self(None);
+
+    fn settings(&self) -> Cow<'_, Settings> {
+        match &self.0 {
+            Some(settings) => Cow::Borrowed(settings),
+            None => Cow::Owned(Settings::default()),
+        }
+    }
+
+    // A line with no digits under the error policy is an error for the part reading it, since
+    // the other part's vocabulary may well find some.
+    fn calibrate(&self, lines: &[String], part: u8) -> ParseResult<u64> {
+        let mut calibration = Calibration::new(part, &self.settings());
+
+        for l in lines {
+            calibration.add(l)?;
+        }
+
+        Ok(calibration.finish())
+    }
+
+    fn scan(&self, input: &str, part: u8) -> ParseResult<u64> {
+        let settings = self.settings();
+        let threads = match settings.threads {
+            Some(0) | None => thread::available_parallelism().map_or(1, |n| n.get()),
+            Some(threads) => threads,
+        };
+
+        scan::calibrate(input, part, threads, &settings)
+    }
+}
+
+impl Solution for Day01 {
+    type Model = Vec<String>;
+    type Part1 = ParseResult<u64>;
+    type Part2 = ParseResult<u64>;
+
+    fn parse(&self, input: &str) -> ParseResult<Self::Model> {
+        Ok(input.lines().map(|l| l.to_string()).collect())
+    }
+
+    fn part1(&self, lines: &Self::Model) -> ParseResult<u64> {
+        self.calibrate(lines, 1)
+    }
+
+    fn part2(&self, lines: &Self::Model) -> ParseResult<u64> {
+        self.calibrate(lines, 2)
+    }
+}
+
+impl Stream for Day01 {
+    type Totals1 = Totals1;
+    type Totals2 = Totals2;
+
+    fn totals1(&self) -> Totals1 {
+        Totals1(Calibration::new(1, &self.settings()))
+    }
+
+    fn totals2(&self) -> Totals2 {
+        Totals2(Calibration::new(2, &self.settings()))
+    }
+
+    fn line1(&self, totals: &mut Totals1, line: &str) -> ParseResult<()> {
+        totals.0.add(line)
+    }
+
+    fn line2(&self, totals: &mut Totals2, line: &str) -> ParseResult<()> {
+        totals.0.add(line)
+    }
+
+    fn answer1(&self, totals: Totals1) -> ParseResult<u64> {
+        Ok(totals.0.finish())
+    }
+
+    fn answer2(&self, totals: Totals2) -> ParseResult<u64> {
+        Ok(totals.0.finish())
+    }
+}
+
+impl Scan for Day01 {
+    fn scanning(&self) -> bool {
+        self.settings().threads.is_some()
+    }
+
+    fn scan1(&self, input: &str) -> ParseResult<u64> {
+        self.scan(input, 1)
+    }
+
+    fn scan2(&self, input: &str) -> ParseResult<u64> {
+        self.scan(input, 2)
+    }
+}
+
+/// Options: `part1` and `part2` name the vocabulary each part reads digits with, and
+/// `vocabularies` is the file to find ones that aren't built in, instead of [`VOCABULARIES`].
+/// `digitless` is what to do with lines with no digits (skip, zero or error), and `explain=true`
+/// prints how each line was read. `threads` splits the input between that many threads (0 for one
+/// per CPU). Either way, `-v` logs the throughput of each part.
+impl Configure for Day01 {
+    fn configure(&self, options: &Options) -> Result<Configured, String> {
+        options.check(&[
+            "part1",
+            "part2",
+            "vocabularies",
+            "digitless",
+            "explain",
+            "threads",
+        ])?;
+
+        let file = PathBuf::from(options.get("vocabularies").unwrap_or(VOCABULARIES));
+        let vocabulary =
+            |part, default| Vocabulary::named(options.get(part).unwrap_or(default), &file);
+
+        let settings = Settings {
+            part1: vocabulary("part1", "digits")?,
+            part2: vocabulary("part2", "english")?,
+            digitless: options.parse("digitless")?.unwrap_or_default(),
+            explain: options.parse("explain")?.unwrap_or_default(),
+            threads: options.parse("threads")?,
+        };
+        if settings.explain && settings.threads.is_some() {
+            return Err(
+                "explain reads a line at a time, so can't be used with threads".to_string(),
+            );
+        }
+
+        let day = Arc::new(Self(Some(settings)));
+        Ok(Configured {
+            solution: day.clone(),
+            stream: Some(day.clone()),
+            scan: Some(day),
+        })
+    }
+}
@@ -0,0 +1,271 @@
+use std::{thread, time::Instant};
+
+use aho_corasick::{AhoCorasick, MatchKind};
+use common::{ParseError, ParseResult};
+use tracing::{info, warn};
+
+use crate::{Digitless, Settings, Vocabulary};
+
+// Finds a line's first digit reading forwards, and its last reading backwards from the end, so
+// neither looks at more of the line than it has to. Leftmost-longest matching gives the same
+// answers as `Calibration` does from all the overlapping matches: the first digit is the one
+// starting first, and the last the one ending last, the longer word winning a tie.
+struct Scanner {
+    forward: AhoCorasick,
+    // The same words reversed, run over the line reversed.
+    backward: AhoCorasick,
+    digits: Vec<u64>,
+}
+
+impl Scanner {
+    fn new(vocabulary: &Vocabulary) -> Self {
+        let build = |patterns: Vec<Vec<u8>>| {
+            AhoCorasick::builder()
+                .match_kind(MatchKind::LeftmostLongest)
+                .build(patterns)
+                .unwrap()
+        };
+
+        Self {
+            forward: build(vocabulary.patterns().map(|p| p.bytes().collect()).collect()),
+            backward: build(
+                vocabulary
+                    .patterns()
+                    .map(|p| p.bytes().rev().collect())
+                    .collect(),
+            ),
+            digits: vocabulary.words().iter().map(|(_, d)| *d as u64).collect(),
+        }
+    }
+
+    // The calibration value of `line`, reversing it into `reversed`.
+    fn value(&self, line: &str, reversed: &mut Vec<u8>) -> Option<u64> {
+        let first = self.forward.find(line)?;
+
+        // Every match starts at or after the first, so there's no need to reverse what's before.
+        reversed.clear();
+        reversed.extend(line.as_bytes()[first.start()..].iter().rev());
+        let last = self.backward.find(&reversed[..])?;
+
+        Some(10 * self.digits[first.pattern()] + self.digits[last.pattern()])
+    }
+}
+
+// What one thread made of its chunk of the input.
+#[derive(Default)]
+struct Partial<'a> {
+    sum: u64,
+    lines: usize,
+    // Numbers of the lines with no digits, counting from the start of the chunk.
+    missing: Vec<usize>,
+    // The first line with no digits, when that's an error. The chunk stops there.
+    error: Option<&'a str>,
+}
+
+fn scan_chunk<'a>(scanner: &Scanner, chunk: &'a str, digitless: Digitless) -> Partial<'a> {
+    let mut partial = Partial::default();
+    let mut reversed = Vec::new();
+
+    for line in chunk.lines() {
+        partial.lines += 1;
+
+        match scanner.value(line, &mut reversed) {
+            Some(value) => partial.sum += value,
+            None if digitless == Digitless::Error => {
+                partial.error = Some(line);
+                break;
+            }
+            None => partial.missing.push(partial.lines),
+        }
+    }
+
+    partial
+}
+
+// `input` cut into about `n` chunks of whole lines.
+fn chunks(input: &str, n: usize) -> Vec<&str> {
+    let size = input.len().div_ceil(n).max(1);
+    let mut chunks = Vec::with_capacity(n);
+    let mut rest = input;
+
+    while !rest.is_empty() {
+        let end = match rest.as_bytes().get(size..) {
+            Some(after) => after
+                .iter()
+                .position(|&b| b == b'\n')
+                .map_or(rest.len(), |i| size + i + 1),
+            None => rest.len(),
+        };
+
+        let (chunk, after) = rest.split_at(end);
+        chunks.push(chunk);
+        rest = after;
+    }
+
+    chunks
+}
+
+/// Part `part`'s sum of calibration values, with the input split between `threads` threads.
+/// Logs how fast it went at info level, to compare with reading a line at a time.
+pub fn calibrate(input: &str, part: u8, threads: usize, settings: &Settings) -> ParseResult<u64> {
+    let start = Instant::now();
+    let scanner = Scanner::new(match part {
+        1 => &settings.part1,
+        _ => &settings.part2,
+    });
+
+    let partials: Vec<Partial> = thread::scope(|s| {
+        let scanner = &scanner;
+        let handles: Vec<_> = chunks(input, threads)
+            .into_iter()
+            .map(|chunk| s.spawn(move || scan_chunk(scanner, chunk, settings.digitless)))
+            .collect();
+
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+
+    let mut sum = 0;
+    let mut lines = 0;
+    for partial in partials {
+        if let Some(line) = partial.error {
+            return Err(ParseError::at(line, format!("a digit (part {part})")));
+        }
+
+        for number in &partial.missing {
+            warn!(
+                part,
+                line = lines + number,
+                "no digits, {}",
+                settings.digitless
+            );
+        }
+
+        sum += partial.sum;
+        lines += partial.lines;
+    }
+
+    let elapsed = start.elapsed();
+    info!(
+        part,
+        "{lines} lines, {:.2} GB in {elapsed:.2?} on {threads} thread{}, {:.2} GB/s",
+        input.len() as f64 / 1e9,
+        if threads == 1 { "" } else { "s" },
+        input.len() as f64 / 1e9 / elapsed.as_secs_f64()
+    );
+
+    Ok(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+    use crate::{calibration::Calibration, VOCABULARIES};
+
+    // The sum reading a line at a time, as streaming does.
+    fn stream(input: &str, part: u8, settings: &Settings) -> ParseResult<u64> {
+        let mut calibration = Calibration::new(part, settings);
+        for line in input.lines() {
+            calibration.add(line)?;
+        }
+        Ok(calibration.finish())
+    }
+
+    #[test]
+    fn chunk_boundaries() {
+        let input = "ab\ncdef\n\ng\nhij\n";
+
+        for n in 1..=input.len() + 1 {
+            let chunks = chunks(input, n);
+            assert_eq!(chunks.concat(), input, "{n} chunks");
+            assert!(chunks.len() <= n, "{n} chunks");
+            // Every chunk is whole lines.
+            assert!(chunks.iter().all(|c| c.ends_with('\n')), "{n} chunks");
+        }
+
+        // The last line needn't end in a newline.
+        assert_eq!(chunks("ab\ncd", 2), ["ab\ncd"]);
+        assert_eq!(chunks("ab\ncd", 5), ["ab\n", "cd"]);
+        assert!(chunks("", 4).is_empty());
+    }
+
+    #[test]
+    fn word_across_chunk_edge() {
+        // However the input is cut, "eightwo" and "seven" are never split between threads.
+        let input = "1eightwo\nseven\nxnine2\n";
+        let settings = Settings::default();
+
+        for threads in 1..=input.len() + 1 {
+            assert_eq!(
+                calibrate(input, 2, threads, &settings),
+                Ok(12 + 77 + 92),
+                "{threads} threads"
+            );
+        }
+    }
+
+    #[test]
+    fn scan_matches_stream() {
+        let settings = Settings {
+            digitless: Digitless::Skip,
+            ..Settings::default()
+        };
+
+        // Words that overlap each other every way round, with digits between them.
+        let pieces = ["one", "eight", "two", "nine", "three", "tw", "ne", "1", "x"];
+        let mut input = String::new();
+        for a in pieces {
+            for b in pieces {
+                for c in pieces {
+                    input.extend([a, b, c, "\n"]);
+                }
+            }
+        }
+
+        for part in [1, 2] {
+            let streamed = stream(&input, part, &settings);
+            for threads in [1, 3, 8] {
+                assert_eq!(calibrate(&input, part, threads, &settings), streamed);
+            }
+        }
+    }
+
+    #[test]
+    fn scan_matches_stream_roman() {
+        let settings = Settings {
+            part2: Vocabulary::named("roman", Path::new(VOCABULARIES)).unwrap(),
+            digitless: Digitless::Skip,
+            ..Settings::default()
+        };
+
+        // Every line of up to five of i, v and x, where nearly every word overlaps another.
+        let mut lines = vec![String::new()];
+        for _ in 0..5 {
+            lines = lines
+                .iter()
+                .flat_map(|l| ['i', 'v', 'x'].map(|c| format!("{l}{c}")))
+                .chain(lines.iter().cloned())
+                .collect();
+        }
+        let input: String = lines.iter().map(|l| format!("{l}\n")).collect();
+
+        let streamed = stream(&input, 2, &settings);
+        assert!(streamed.as_ref().is_ok_and(|&sum| sum > 0));
+        for threads in [1, 4] {
+            assert_eq!(calibrate(&input, 2, threads, &settings), streamed);
+        }
+    }
+
+    #[test]
+    fn digitless_error() {
+        let input = "1abc2\nno digits\n";
+        let e = calibrate(input, 1, 2, &Settings::default())
+            .unwrap_err()
+            .locate(input);
+
+        assert_eq!(e.expected, "a digit (part 1)");
+        let position = e.position.unwrap();
+        assert_eq!((position.line, position.column), (2, 1));
+    }
+}
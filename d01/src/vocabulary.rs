@@ -0,0 +1,239 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use serde::Deserialize;
+
+/// The bundled vocabularies file, used when the options don't name another.
+pub const VOCABULARIES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/vocabularies.toml");
+
+const SPELLED: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// The words a part reads as digits, and the digit each stands for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vocabulary {
+    words: Vec<(String, u32)>,
+}
+
+// One vocabulary as written in the file.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Entry {
+    extends: Option<String>,
+    #[serde(default)]
+    words: BTreeMap<String, u32>,
+}
+
+impl Vocabulary {
+    /// "1" to "9", the original part 1 rule.
+    pub fn digits() -> Self {
+        Self {
+            words: (1..=9).map(|d| (d.to_string(), d)).collect(),
+        }
+    }
+
+    /// The digits, and also "one" to "nine" spelled out.
+    pub fn english() -> Self {
+        let mut english = Self::digits();
+        english.extend(SPELLED.iter().zip(1..).map(|(w, d)| (w.to_string(), d)));
+        english
+    }
+
+    /// The vocabulary called `name`, either built in or from the vocabularies in `file`. The file
+    /// is only read for names that aren't built in.
+    pub fn named(name: &str, file: &Path) -> Result<Self, String> {
+        if let Some(builtin) = Self::builtin(name) {
+            return Ok(builtin);
+        }
+
+        let entries = fs::read_to_string(file).map_err(|e| format!("{}: {e}", file.display()))?;
+        let entries: BTreeMap<String, Entry> =
+            toml::from_str(&entries).map_err(|e| format!("{}: {e}", file.display()))?;
+
+        Self::resolve(name, &entries, &mut Vec::new())
+            .map_err(|e| format!("{}: {e}", file.display()))
+    }
+
+    /// Each word and its digit. A word's index here is its pattern in a matcher built from
+    /// [`patterns`](Self::patterns).
+    pub fn words(&self) -> &[(String, u32)] {
+        &self.words
+    }
+
+    pub fn patterns(&self) -> impl Iterator<Item = &str> {
+        self.words.iter().map(|(w, _)| w.as_str())
+    }
+
+    fn builtin(name: &str) -> Option<Self> {
+        match name {
+            "digits" => Some(Self::digits()),
+            "english" => Some(Self::english()),
+            _ => None,
+        }
+    }
+
+    // `name` from the file's `entries`, following `extends`. `seen` holds the names already on
+    // the way here, to catch vocabularies that extend themselves.
+    fn resolve(
+        name: &str,
+        entries: &BTreeMap<String, Entry>,
+        seen: &mut Vec<String>,
+    ) -> Result<Self, String> {
+        if seen.iter().any(|s| s == name) {
+            return Err(format!("vocabulary {name:?} extends itself"));
+        }
+
+        let Some(entry) = entries.get(name) else {
+            if let Some(builtin) = Self::builtin(name) {
+                return Ok(builtin);
+            }
+
+            let mut known = vec!["digits", "english"];
+            known.extend(entries.keys().map(|k| k.as_str()));
+            return Err(format!(
+                "no vocabulary {name:?}, expected one of {}",
+                known.join(", ")
+            ));
+        };
+
+        if let Some((word, digit)) = entry.words.iter().find(|(w, d)| w.is_empty() || **d > 9) {
+            return Err(format!(
+                "vocabulary {name:?} has {word:?} = {digit}, expected a word and a digit"
+            ));
+        }
+
+        seen.push(name.to_string());
+        let mut vocabulary = match &entry.extends {
+            Some(base) => Self::resolve(base, entries, seen)?,
+            None => Self { words: Vec::new() },
+        };
+        seen.pop();
+
+        vocabulary.extend(entry.words.iter().map(|(w, d)| (w.clone(), *d)));
+        if vocabulary.words.is_empty() {
+            return Err(format!("vocabulary {name:?} has no words"));
+        }
+
+        Ok(vocabulary)
+    }
+
+    // Add `words`, replacing the digit of any already here.
+    fn extend(&mut self, words: impl IntoIterator<Item = (String, u32)>) {
+        for (word, digit) in words {
+            match self.words.iter_mut().find(|(w, _)| *w == word) {
+                Some(existing) => existing.1 = digit,
+                None => self.words.push((word, digit)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // `name` from vocabularies written as they would be in the file.
+    fn resolve(file: &str, name: &str) -> Result<Vocabulary, String> {
+        let entries: BTreeMap<String, Entry> = toml::from_str(file).map_err(|e| e.to_string())?;
+        Vocabulary::resolve(name, &entries, &mut Vec::new())
+    }
+
+    #[test]
+    fn extends_chain() {
+        let file = r#"
+            [a]
+            extends = "digits"
+            words = { uno = 1 }
+            [b]
+            extends = "a"
+            words = { dos = 2, uno = 7 }
+        "#;
+        let b = resolve(file, "b").unwrap();
+
+        // The base's words come first, and a word given again takes the new digit.
+        let mut expected = Vocabulary::digits().words().to_vec();
+        expected.extend([("uno".to_string(), 7), ("dos".to_string(), 2)]);
+        assert_eq!(b.words(), expected);
+
+        assert_eq!(resolve(file, "english"), Ok(Vocabulary::english()));
+    }
+
+    #[test]
+    fn extends_cycle() {
+        let file = r#"
+            [a]
+            extends = "b"
+            words = { x = 1 }
+            [b]
+            extends = "c"
+            [c]
+            extends = "a"
+        "#;
+        assert_eq!(
+            resolve(file, "a"),
+            Err("vocabulary \"a\" extends itself".to_string())
+        );
+
+        let file = "[a]\nextends = \"a\"\nwords = { x = 1 }\n";
+        assert_eq!(
+            resolve(file, "a"),
+            Err("vocabulary \"a\" extends itself".to_string())
+        );
+    }
+
+    #[test]
+    fn unknown_base() {
+        let file = "[a]\nextends = \"klingon\"\nwords = { x = 1 }\n";
+        assert_eq!(
+            resolve(file, "a"),
+            Err("no vocabulary \"klingon\", expected one of digits, english, a".to_string())
+        );
+        assert!(resolve(file, "b")
+            .unwrap_err()
+            .starts_with("no vocabulary \"b\""));
+    }
+
+    #[test]
+    fn invalid_entries() {
+        // No words of its own and nothing to extend.
+        assert_eq!(
+            resolve("[a]\n", "a"),
+            Err("vocabulary \"a\" has no words".to_string())
+        );
+        assert_eq!(
+            resolve("[a]\nwords = { \"\" = 1 }\n", "a"),
+            Err("vocabulary \"a\" has \"\" = 1, expected a word and a digit".to_string())
+        );
+        assert_eq!(
+            resolve("[a]\nwords = { ten = 10 }\n", "a"),
+            Err("vocabulary \"a\" has \"ten\" = 10, expected a word and a digit".to_string())
+        );
+        // A bad entry is caught even when it's only a base.
+        assert_eq!(
+            resolve("[a]\nwords = { x = 10 }\n[b]\nextends = \"a\"\n", "b"),
+            Err("vocabulary \"a\" has \"x\" = 10, expected a word and a digit".to_string())
+        );
+        assert_eq!(
+            resolve("[a]\n[b]\nextends = \"a\"\nwords = { x = 1 }\n", "b"),
+            Err("vocabulary \"a\" has no words".to_string())
+        );
+
+        // Entries the file can't hold: an unknown field, and a digit that isn't one.
+        assert!(resolve("[a]\nword = { x = 1 }\n", "a").is_err());
+        assert!(resolve("[a]\nwords = { x = -1 }\n", "a").is_err());
+        assert!(resolve("[a]\nwords = { x = \"one\" }\n", "a").is_err());
+    }
+
+    #[test]
+    fn bundled() {
+        let file = Path::new(VOCABULARIES);
+        assert_eq!(Vocabulary::named("digits", file), Ok(Vocabulary::digits()));
+
+        let german = Vocabulary::named("german", file).unwrap();
+        assert_eq!(german.words().len(), 18);
+        assert!(german.words().contains(&("fünf".to_string(), 5)));
+
+        assert!(Vocabulary::named("english", Path::new("missing.toml")).is_ok());
+        assert!(Vocabulary::named("german", Path::new("missing.toml")).is_err());
+    }
+}
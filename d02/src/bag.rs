@@ -0,0 +1,147 @@
+use std::{fmt::Display, str::FromStr};
+
+use common::{
+    parse::{list, space1, tag, unsigned, word, Parsed, Parser},
+    ParseError,
+};
+
+/// The colours of cube there are, and how many of each a bag holds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bag {
+    colours: Vec<String>,
+    counts: Vec<u32>,
+}
+
+/// Counts of cubes of each of a [`Bag`]'s colours, in the same order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Balls(pub Vec<u32>);
+
+// "3 blue, 4 red", as counts and colours.
+fn counts(s: &str) -> Parsed<'_, Vec<(u32, &str)>> {
+    let ball = |s| {
+        let (count, s) = unsigned::<u32>(s)?;
+        let (_, s) = space1(s)?;
+        let (colour, s) = word(s)?;
+
+        Ok(((count, colour), s))
+    };
+
+    list(ball, tag(", ")).parse(s)
+}
+
+impl Bag {
+    /// The puzzle's bag: 12 red, 13 green and 14 blue.
+    pub fn new() -> Self {
+        "12 red, 13 green, 14 blue".parse().unwrap()
+    }
+
+    pub fn colours(&self) -> &[String] {
+        &self.colours
+    }
+
+    /// Whether the bag holds at least `balls`.
+    pub fn holds(&self, balls: &Balls) -> bool {
+        self.shortfall(balls).is_none()
+    }
+
+    /// The first colour there are more of in `balls` than the bag holds.
+    pub fn shortfall(&self, balls: &Balls) -> Option<usize> {
+        balls
+            .0
+            .iter()
+            .zip(&self.counts)
+            .position(|(n, held)| n > held)
+    }
+
+    /// `balls` written out like a trial, with the colours there are none of left out.
+    pub fn describe(&self, balls: &Balls) -> String {
+        let counts: Vec<String> = self
+            .colours
+            .iter()
+            .zip(&balls.0)
+            .filter(|(_, count)| **count > 0)
+            .map(|(colour, count)| format!("{count} {colour}"))
+            .collect();
+
+        counts.join(", ")
+    }
+
+    /// `other`'s counts in this bag's colours, holding none of any it doesn't name. Fails on a
+    /// colour this bag doesn't have, since no game can use it.
+    pub fn restock(&self, other: &Bag) -> Result<Bag, String> {
+        let mut bag = Self {
+            colours: self.colours.clone(),
+            counts: vec![0; self.colours.len()],
+        };
+
+        for (colour, count) in other.colours.iter().zip(&other.counts) {
+            let Some(idx) = self.colours.iter().position(|c| c == colour) else {
+                return Err(format!(
+                    "{colour} isn't one of the colours ({})",
+                    self.colours.join(", ")
+                ));
+            };
+            bag.counts[idx] = *count;
+        }
+
+        Ok(bag)
+    }
+
+    /// Parse one trial, e.g. "3 blue, 4 red", whose colours must be this bag's, each at most once.
+    pub fn balls<'a>(&self, s: &'a str) -> Parsed<'a, Balls> {
+        let (counts, rest) = counts(s)?;
+        let mut balls = Balls(vec![0; self.colours.len()]);
+        let mut seen = vec![false; self.colours.len()];
+
+        for (count, colour) in counts {
+            let Some(idx) = self.colours.iter().position(|c| c == colour) else {
+                return Err(ParseError::at(
+                    colour,
+                    format!("a colour ({})", self.colours.join(", ")),
+                ));
+            };
+            if seen[idx] {
+                return Err(ParseError::at(colour, "a colour not already in the trial"));
+            }
+            seen[idx] = true;
+            balls.0[idx] = count;
+        }
+
+        Ok((balls, rest))
+    }
+}
+
+impl Default for Bag {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Parses a bag written like a trial, e.g. "12 red, 13 green, 14 blue". Its colours are the ones
+/// games may use.
+impl FromStr for Bag {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut bag = Self {
+            colours: Vec::new(),
+            counts: Vec::new(),
+        };
+
+        for (count, colour) in counts.all(s.trim())? {
+            if bag.colours.iter().any(|c| c == colour) {
+                return Err(ParseError::at(colour, "a colour not already in the bag"));
+            }
+            bag.colours.push(colour.to_string());
+            bag.counts.push(count);
+        }
+
+        Ok(bag)
+    }
+}
+
+impl Display for Bag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.describe(&Balls(self.counts.clone())))
+    }
+}
@@ -0,0 +1,279 @@
+use std::{borrow::Cow, sync::Arc};
+
+use common::{
+    parse::{labelled, list, tag, unsigned, Parser},
+    Configure, Configured, Options, ParseResult, Solution, Stream,
+};
+
+pub use bag::{Bag, Balls};
+pub use table::Table;
+
+mod bag;
+mod table;
+
+pub const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+// The bag, and whether to print a table of the games under it and the other `candidates`.
+#[derive(Clone, Default)]
+struct Settings {
+    bag: Bag,
+    table: bool,
+    candidates: Vec<Bag>,
+}
+
+impl Settings {
+    fn table(&self) -> Option<Table> {
+        let bags = [self.bag.clone()]
+            .into_iter()
+            .chain(self.candidates.clone());
+        self.table.then(|| Table::new(bags.collect()))
+    }
+}
+
+#[derive(Debug)]
+pub struct Game {
+    id: u32,
+    trials: Vec<Balls>,
+}
+
+impl Game {
+    // "Game 1: 3 blue, 4 red; 1 red, 2 green", in `bag`'s colours.
+    pub fn from_line(line: &str, bag: &Bag) -> ParseResult<Self> {
+        let (id, rest) = labelled("Game", unsigned).parse(line)?;
+        let (_, rest) = tag(": ").parse(rest)?;
+        let trials = list(|s| bag.balls(s), tag("; ")).all(rest)?;
+
+        Ok(Self { id, trials })
+    }
+}
+
+fn is_valid_trial(balls: &Balls, bag: &Bag) -> bool {
+    bag.holds(balls)
+}
+
+fn is_possible(game: &Game, bag: &Bag) -> bool {
+    game.trials.iter().all(|t| is_valid_trial(t, bag))
+}
+
+// The fewest of each colour the game could have been played with.
+fn minimal_bag(game: &Game) -> Balls {
+    let mut min = game.trials[0].clone();
+    for trial in &game.trials[1..] {
+        for (m, n) in min.0.iter_mut().zip(&trial.0) {
+            *m = (*m).max(*n);
+        }
+    }
+
+    min
+}
+
+// The product of the minimal bag over the colours the game uses. A bag colour no game draws would
+// otherwise make every power 0.
+fn get_game_power(game: &Game) -> u32 {
+    minimal_bag(game).0.iter().filter(|n| **n > 0).product()
+}
+
+/// Day 2, with the settings from its options, or the puzzle's if it hasn't any.
+pub struct Day02(Option<Settings>);
+
+impl Day02 {
+    /// The day as the puzzle sets it.
+    pub const PUZZLE: Self = Self(None);
+
+    fn settings(&self) -> Cow<'_, Settings> {
+        match &self.0 {
+            Some(settings) => Cow::Borrowed(settings),
+            None => Cow::Owned(Settings::default()),
+        }
+    }
+}
+
+impl Solution for Day02 {
+    type Model = Vec<Game>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(&self, input: &str) -> ParseResult<Self::Model> {
+        let settings = self.settings();
+        input
+            .lines()
+            .map(|l| Game::from_line(l, &settings.bag))
+            .collect()
+    }
+
+    fn part1(&self, games: &Self::Model) -> u32 {
+        let settings = self.settings();
+
+        games
+            .iter()
+            .filter(|g| is_possible(g, &settings.bag))
+            .map(|g| g.id)
+            .sum()
+    }
+
+    fn part2(&self, games: &Self::Model) -> u32 {
+        games.iter().map(get_game_power).sum()
+    }
+
+    fn report(&self, games: &Self::Model) -> Option<String> {
+        let mut table = self.settings().table()?;
+        for game in games {
+            table.add(game);
+        }
+
+        Some(table.render())
+    }
+}
+
+/// Part 1's running total, with the bag to read the games with and the table of them so far if
+/// it's printing it.
+pub struct Ids {
+    bag: Bag,
+    sum: u32,
+    table: Option<Table>,
+}
+
+/// Part 2's running total, with the bag to read the games with and the table of them so far if
+/// it's printing it.
+pub struct Power {
+    bag: Bag,
+    sum: u32,
+    table: Option<Table>,
+}
+
+impl Stream for Day02 {
+    type Totals1 = Ids;
+    type Totals2 = Power;
+
+    fn totals1(&self) -> Ids {
+        let settings = self.settings();
+        Ids {
+            table: settings.table(),
+            bag: settings.bag.clone(),
+            sum: 0,
+        }
+    }
+
+    fn totals2(&self) -> Power {
+        Power {
+            bag: self.settings().bag.clone(),
+            sum: 0,
+            table: None,
+        }
+    }
+
+    // The table goes with part 1 if it's running and part 2 otherwise, so it's printed once.
+    fn totals(&self, parts: &[u8]) -> (Option<Ids>, Option<Power>) {
+        let ids = parts.contains(&1).then(|| self.totals1());
+        let mut power = parts.contains(&2).then(|| self.totals2());
+        if let (None, Some(power)) = (&ids, &mut power) {
+            power.table = self.settings().table();
+        }
+
+        (ids, power)
+    }
+
+    fn line1(&self, ids: &mut Ids, line: &str) -> ParseResult<()> {
+        let game = Game::from_line(line, &ids.bag)?;
+        if is_possible(&game, &ids.bag) {
+            ids.sum += game.id;
+        }
+        if let Some(table) = &mut ids.table {
+            table.add(&game);
+        }
+
+        Ok(())
+    }
+
+    fn line2(&self, power: &mut Power, line: &str) -> ParseResult<()> {
+        let game = Game::from_line(line, &power.bag)?;
+        power.sum += get_game_power(&game);
+        if let Some(table) = &mut power.table {
+            table.add(&game);
+        }
+
+        Ok(())
+    }
+
+    fn answer1(&self, ids: Ids) -> u32 {
+        if let Some(table) = ids.table {
+            eprint!("{}", table.render());
+        }
+
+        ids.sum
+    }
+
+    fn answer2(&self, power: Power) -> u32 {
+        if let Some(table) = power.table {
+            eprint!("{}", table.render());
+        }
+
+        power.sum
+    }
+}
+
+/// Options: `bag` is what's in the bag, e.g. "12 red, 13 green, 14 blue, 3 purple", and so which
+/// colours the games may use. Part 2's power is the product over the colours each game uses. With `table=true`,
+/// whichever parts run print each game's minimal bag and whether it's possible with the bag and
+/// each of the `candidates`, e.g. "20 red, 20 green, 20 blue; 12 red", once between them.
+impl Configure for Day02 {
+    fn configure(&self, options: &Options) -> Result<Configured, String> {
+        options.check(&["bag", "table", "candidates"])?;
+
+        let parse = |name: &str, bag: &str| bag.parse::<Bag>().map_err(|e| format!("{name}: {e}"));
+
+        let bag = match options.get("bag") {
+            Some(bag) => parse("bag", bag)?,
+            None => Bag::new(),
+        };
+        let candidates = match options.get("candidates") {
+            Some(candidates) => candidates
+                .split(';')
+                .map(|c| {
+                    bag.restock(&parse("candidates", c)?)
+                        .map_err(|e| format!("candidates: {e}"))
+                })
+                .collect::<Result<_, _>>()?,
+            None => Vec::new(),
+        };
+        let settings = Settings {
+            bag,
+            table: options.parse("table")?.unwrap_or_default(),
+            candidates,
+        };
+        let day = Arc::new(Self(Some(settings)));
+        Ok(Configured {
+            solution: day.clone(),
+            stream: Some(day),
+            scan: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Options;
+
+    use super::*;
+
+    #[test]
+    fn repeated_colour() {
+        let input = "Game 1: 3 blue, 4 red; 1 red, 2 blue, 1 red\n";
+        let e = Day02::PUZZLE.parse(input).unwrap_err().locate(input);
+
+        let position = e.position.unwrap();
+        assert_eq!((position.line, position.column), (1, 41));
+        assert_eq!(e.expected, "a colour not already in the trial");
+    }
+
+    #[test]
+    fn power_over_used_colours() {
+        let mut options = Options::new();
+        options.set("bag", "12 red, 13 green, 14 blue, 5 purple");
+        let day = Day02::PUZZLE.configure(&options).unwrap().solution;
+
+        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue\nGame 2: 1 purple, 2 red\n";
+        let games = day.parse(input).unwrap();
+        assert_eq!(day.part2(games.as_ref()), Ok((4 * 2 * 6 + 2).to_string()));
+    }
+}
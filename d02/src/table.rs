@@ -0,0 +1,69 @@
+use crate::{minimal_bag, Bag, Game};
+
+/// An audit of the games one by one: each game's minimal bag, and whether it's possible with each
+/// of the candidate bags, or else which trial and colour rule it out.
+pub struct Table {
+    candidates: Vec<Bag>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(candidates: Vec<Bag>) -> Self {
+        Self {
+            candidates,
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn add(&mut self, game: &Game) {
+        // Every candidate is in the same colours as the bag the games were read with.
+        let colours = &self.candidates[0];
+
+        let mut row = vec![game.id.to_string(), colours.describe(&minimal_bag(game))];
+        for candidate in &self.candidates {
+            let shortfall = game
+                .trials
+                .iter()
+                .enumerate()
+                .find_map(|(idx, t)| Some((idx, t, candidate.shortfall(t)?)));
+
+            row.push(match shortfall {
+                Some((idx, trial, colour)) => format!(
+                    "no: trial {} has {} {}",
+                    idx + 1,
+                    trial.0[colour],
+                    colours.colours()[colour]
+                ),
+                None => "yes".to_string(),
+            });
+        }
+
+        self.rows.push(row);
+    }
+
+    /// The table, a line per game under a header.
+    pub fn render(&self) -> String {
+        let mut header = vec!["game".to_string(), "minimal bag".to_string()];
+        header.extend(self.candidates.iter().map(|c| c.to_string()));
+
+        let widths: Vec<usize> = (0..header.len())
+            .map(|col| {
+                let cells = self.rows.iter().map(|r| r[col].len());
+                cells.chain([header[col].len()]).max().unwrap_or_default()
+            })
+            .collect();
+
+        let mut out = String::new();
+        for row in [&header].into_iter().chain(&self.rows) {
+            let cells: Vec<String> = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{cell:width$}"))
+                .collect();
+            out += cells.join("  ").trim_end();
+            out += "\n";
+        }
+
+        out
+    }
+}
@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+
+use common::{parse::unsigned, ParseError, ParseResult, Solution};
+use tracing::trace;
+
+pub const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+#[derive(PartialEq, Eq, Hash, Debug)]
+struct Position {
+    line: usize,
+    col: usize,
+}
+
+#[derive(PartialEq, Eq, Hash, Debug)]
+struct Symbol {
+    is_gear: bool,
+    numbers: Vec<u32>,
+}
+
+fn add_number_if_exists(
+    symbols: &mut HashMap<Position, Symbol>,
+    line: usize,
+    col: usize,
+    number: u32,
+) -> bool {
+    match symbols.get_mut(&Position { line, col }) {
+        Some(symbol) => {
+            symbol.numbers.push(number);
+            true
+        }
+        None => false,
+    }
+}
+
+fn record_number(
+    symbols: &mut HashMap<Position, Symbol>,
+    line_num: usize,
+    start: usize,
+    end: usize,
+    number: u32,
+) -> bool {
+    let left_search_start = match start {
+        0 => 0,
+        _ => start - 1,
+    };
+
+    let mut found = false;
+
+    // First, check the same line
+    found |= add_number_if_exists(symbols, line_num, left_search_start, number);
+    found |= add_number_if_exists(symbols, line_num, end + 1, number);
+
+    // Above
+    if line_num != 0 {
+        for col in left_search_start..=end + 1 {
+            found |= add_number_if_exists(symbols, line_num - 1, col, number);
+        }
+    }
+
+    // Below
+    for col in left_search_start..=end + 1 {
+        found |= add_number_if_exists(symbols, line_num + 1, col, number);
+    }
+
+    found
+}
+
+pub struct Schematic {
+    symbols: HashMap<Position, Symbol>,
+    // Numbers adjacent to at least one symbol.
+    part_numbers: Vec<u32>,
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Model = Schematic;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(&self, input: &str) -> ParseResult<Self::Model> {
+        let mut symbols: HashMap<Position, Symbol> = HashMap::new();
+
+        for (line_num, l) in input.lines().enumerate() {
+            for (col_num, (idx, c)) in l.char_indices().enumerate() {
+                match c {
+                    '0'..='9' => {}
+                    '.' => {}
+                    _ if c.is_whitespace() => {
+                        return Err(ParseError::at(
+                            &l[idx..idx + c.len_utf8()],
+                            "a digit, '.' or a symbol",
+                        ));
+                    }
+                    _ => {
+                        symbols.insert(
+                            Position {
+                                line: line_num,
+                                col: col_num,
+                            },
+                            Symbol {
+                                is_gear: c == '*',
+                                numbers: vec![],
+                            },
+                        );
+                    }
+                }
+            }
+        }
+
+        let mut part_numbers = Vec::new();
+
+        for (line_num, l) in input.lines().enumerate() {
+            trace!(line_num, "scanning for part numbers");
+
+            let mut rest = l;
+            while let Some(start) = rest.find(|c: char| c.is_ascii_digit()) {
+                let (number, after) = unsigned::<u32>(&rest[start..])?;
+                let (start, end) = (l.len() - rest.len() + start, l.len() - after.len() - 1);
+                if record_number(&mut symbols, line_num, start, end, number) {
+                    part_numbers.push(number);
+                }
+
+                rest = after;
+            }
+        }
+
+        Ok(Schematic {
+            symbols,
+            part_numbers,
+        })
+    }
+
+    fn part1(&self, schematic: &Self::Model) -> u32 {
+        schematic.part_numbers.iter().sum()
+    }
+
+    fn part2(&self, schematic: &Self::Model) -> u32 {
+        let mut sum = 0;
+
+        for symbol in schematic.symbols.values() {
+            if symbol.is_gear && symbol.numbers.len() == 2 {
+                sum += symbol.numbers[0] * symbol.numbers[1];
+            }
+        }
+
+        sum
+    }
+}
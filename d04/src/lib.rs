@@ -0,0 +1,125 @@
+use std::collections::{HashSet, VecDeque};
+
+use common::{
+    parse::{labelled, list, space0, space1, unsigned, Parsed, Parser},
+    ParseResult, Solution, Stream,
+};
+use tracing::trace;
+
+pub const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+pub struct Card {
+    winning: HashSet<u32>,
+    actual: HashSet<u32>,
+}
+
+impl Card {
+    fn parse_numbers(s: &str) -> Parsed<'_, HashSet<u32>> {
+        trace!("numbers: {s}");
+        list(unsigned, space1).map(HashSet::from_iter).parse(s)
+    }
+
+    // "Card 1: 41 48 83 | 83 86  6"
+    pub fn new(line: &str) -> ParseResult<Self> {
+        let (_, rest) = labelled("Card", unsigned::<u32>).parse(line)?;
+        let (winning, rest) = labelled(":", Self::parse_numbers).parse(rest)?;
+        let (_, rest) = space0(rest)?;
+        let actual = labelled("|", Self::parse_numbers).all(rest)?;
+
+        Ok(Self { winning, actual })
+    }
+
+    pub fn matches(&self) -> u32 {
+        self.winning.intersection(&self.actual).count() as u32
+    }
+
+    pub fn points(&self) -> u32 {
+        match self.matches() {
+            0 => 0,
+            n => 1 << (n - 1),
+        }
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Model = Vec<Card>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(&self, input: &str) -> ParseResult<Self::Model> {
+        input.lines().map(Card::new).collect()
+    }
+
+    fn part1(&self, cards: &Self::Model) -> u32 {
+        cards.iter().map(Card::points).sum()
+    }
+
+    fn part2(&self, cards: &Self::Model) -> u32 {
+        let mut counts: Vec<u32> = vec![1; cards.len()];
+
+        for (idx, card) in cards.iter().enumerate() {
+            let matches = card.matches();
+
+            for next_count in 1..=matches {
+                let next_idx = idx as u32 + next_count;
+                if next_idx as usize >= cards.len() {
+                    break;
+                }
+                counts[next_idx as usize] += counts[idx];
+            }
+        }
+
+        counts.iter().sum()
+    }
+}
+
+// The cards counted so far, and the copies won of the cards still to come, starting with the
+// next one.
+#[derive(Default)]
+pub struct Copies {
+    cards: u32,
+    won: VecDeque<u32>,
+}
+
+impl Stream for Day04 {
+    type Totals1 = u32;
+    type Totals2 = Copies;
+
+    fn totals1(&self) -> u32 {
+        0
+    }
+
+    fn totals2(&self) -> Copies {
+        Copies::default()
+    }
+
+    fn line1(&self, points: &mut u32, line: &str) -> ParseResult<()> {
+        *points += Card::new(line)?.points();
+        Ok(())
+    }
+
+    fn line2(&self, copies: &mut Copies, line: &str) -> ParseResult<()> {
+        let matches = Card::new(line)?.matches() as usize;
+        let these = 1 + copies.won.pop_front().unwrap_or(0);
+
+        copies.cards += these;
+        if copies.won.len() < matches {
+            copies.won.resize(matches, 0);
+        }
+        for won in copies.won.iter_mut().take(matches) {
+            *won += these;
+        }
+
+        Ok(())
+    }
+
+    fn answer1(&self, points: u32) -> u32 {
+        points
+    }
+
+    fn answer2(&self, copies: Copies) -> u32 {
+        copies.cards
+    }
+}
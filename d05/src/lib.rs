@@ -0,0 +1,130 @@
+use std::fmt::Display;
+
+use common::{
+    parse::{
+        block, blocks, labelled, list, section, space1, tag, take_while1, unsigned, Parsed, Parser,
+    },
+    ParseResult, Solution,
+};
+use interval::{Interval, IntervalSet};
+use tracing::trace;
+
+pub const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+// Where one range of a map sends its values.
+#[derive(Debug)]
+struct MapRange {
+    src: Interval<i64>,
+    delta: i64,
+}
+
+impl Display for MapRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{} -> delta {}]", self.src, self.delta)
+    }
+}
+
+impl MapRange {
+    pub fn new(src: i64, dst: i64, len: i64) -> Self {
+        Self {
+            src: Interval::with_len(src, len),
+            delta: dst - src,
+        }
+    }
+}
+
+// Send every value in `values` through `map`. Values no range covers map to themselves.
+fn map_values(values: &IntervalSet<i64>, map: &[MapRange]) -> IntervalSet<i64> {
+    trace!("mapping {values} through {}", joined(map));
+
+    let mut unmapped = values.clone();
+    let mut mapped = IntervalSet::new();
+
+    for mr in map {
+        let src = IntervalSet::from(mr.src);
+        mapped = mapped.union(&unmapped.intersection(&src).shift(mr.delta));
+        unmapped = unmapped.difference(&src);
+    }
+
+    let result = mapped.union(&unmapped);
+    trace!("result: {result}");
+
+    result
+}
+
+fn seed_ranges(digits: &[i64]) -> IntervalSet<i64> {
+    digits
+        .chunks(2)
+        .map(|pair| Interval::with_len(pair[0], pair[1]))
+        .collect()
+}
+
+// "seed-to-soil map:", then a range per line.
+fn parse_map(s: &str) -> Parsed<'_, Vec<MapRange>> {
+    let header = |l| {
+        let (_, l) = take_while1(|c| c != ' ', "\"<from>-to-<to>\"").parse(l)?;
+        tag(" map:").parse(l)
+    };
+    let range = |l| {
+        let (dst, l) = unsigned(l)?;
+        let (src, l) = labelled(" ", unsigned).parse(l)?;
+        let (len, l) = labelled(" ", unsigned).parse(l)?;
+
+        Ok((MapRange::new(src, dst, len), l))
+    };
+
+    section(header, range).map(|(_, ranges)| ranges).parse(s)
+}
+
+fn joined<T>(v: &[T]) -> String
+where
+    T: ToString,
+{
+    v.iter()
+        .map(|mr| mr.to_string())
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+pub struct Almanac {
+    seeds: Vec<i64>,
+    // seed-to-soil, soil-to-fertilizer, ..., humidity-to-location
+    maps: Vec<Vec<MapRange>>,
+}
+
+fn lowest_location(seeds: IntervalSet<i64>, maps: &[Vec<MapRange>]) -> i64 {
+    let locations = maps
+        .iter()
+        .fold(seeds, |values, map| map_values(&values, map));
+
+    locations.min().unwrap()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Model = Almanac;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, input: &str) -> ParseResult<Self::Model> {
+        let seeds = labelled("seeds:", list(unsigned, space1));
+        let (seeds, rest) = block(seeds).parse(input)?;
+        let maps = blocks(parse_map).all(rest)?;
+
+        Ok(Almanac { seeds, maps })
+    }
+
+    fn part1(&self, almanac: &Self::Model) -> i64 {
+        let seeds = almanac
+            .seeds
+            .iter()
+            .map(|s| Interval::with_len(*s, 1))
+            .collect();
+        lowest_location(seeds, &almanac.maps)
+    }
+
+    fn part2(&self, almanac: &Self::Model) -> i64 {
+        lowest_location(seed_ranges(&almanac.seeds), &almanac.maps)
+    }
+}
@@ -0,0 +1,71 @@
+use common::{
+    parse::{labelled, line, list, space1, unsigned, Parser},
+    ParseError, ParseResult, Solution,
+};
+use tracing::debug;
+
+mod p1;
+mod p2;
+
+pub const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+pub struct Race {
+    time: i64,
+    distance: i64,
+}
+
+impl Race {
+    pub fn wins_possible(&self) -> usize {
+        let det = f64::sqrt((self.time.pow(2) - (4 * self.distance)) as f64);
+
+        let r1 = (-self.time as f64 + det) / -2_f64;
+        let r2 = (-self.time as f64 - det) / -2_f64;
+
+        let first = r1.ceil() as i64;
+        let last = r2.floor() as i64;
+
+        debug!(
+            self.time,
+            self.distance, "wins holding from {first} to {last}"
+        );
+
+        (first..=last).count()
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Model = Vec<Race>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> ParseResult<Self::Model> {
+        let (times, rest) = line(labelled("Time:", list(unsigned::<i64>, space1))).parse(input)?;
+        let dists = line(labelled("Distance:", list(unsigned::<i64>, space1))).all(rest)?;
+
+        if times.len() != dists.len() {
+            return Err(ParseError::after(
+                input.trim_end(),
+                format!("{} distances", times.len()),
+            ));
+        }
+
+        Ok(times
+            .iter()
+            .zip(dists.iter())
+            .map(|(t, d)| Race {
+                time: *t,
+                distance: *d,
+            })
+            .collect())
+    }
+
+    fn part1(&self, races: &Self::Model) -> usize {
+        p1::p1(races)
+    }
+
+    fn part2(&self, races: &Self::Model) -> usize {
+        p2::p2(races)
+    }
+}
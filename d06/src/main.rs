@@ -1,6 +0,0 @@
-mod p1;
-mod p2;
-
-fn main() {
-    p2::p2().unwrap();
-}
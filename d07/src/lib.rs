@@ -0,0 +1,142 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use common::{
+    parse::{space1, symbols, unsigned, Parser},
+    ParseError, ParseResult, Solution,
+};
+use tracing::trace;
+
+mod p1;
+mod p2;
+
+pub const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+const CARDS: &str = "23456789TJQKA";
+
+const TYPE_NAMES: [&str; 7] = [
+    "high card",
+    "one pair",
+    "two pair",
+    "three of a kind",
+    "full house",
+    "four of a kind",
+    "five of a kind",
+];
+
+#[derive(Debug)]
+pub struct Hand {
+    cards_ordered: Vec<char>,
+    cards: HashMap<char, usize>,
+    bid: u64,
+}
+
+impl Hand {
+    // "32T3K 765"
+    pub fn new(line: &str) -> ParseResult<Self> {
+        let card = |c| CARDS.contains(c).then_some(c);
+        let (cards_ordered, rest) = symbols(card, "a card").parse(line)?;
+        if cards_ordered.len() != 5 {
+            return Err(ParseError::at(
+                &line[..line.len() - rest.len()],
+                "a hand of 5 cards",
+            ));
+        }
+
+        let (_, rest) = space1(rest)?;
+        let bid = unsigned.all(rest)?;
+
+        let mut cards: HashMap<char, usize> = HashMap::new();
+        for c in &cards_ordered {
+            *cards.entry(*c).or_insert(0) += 1;
+        }
+
+        Ok(Self {
+            cards,
+            cards_ordered,
+            bid,
+        })
+    }
+}
+
+// Ranks a set of card counts from 0 (high card) to 6 (five of a kind).
+fn hand_type(cards: &HashMap<char, usize>) -> u64 {
+    if cards.values().any(|n| *n == 5) {
+        return 6;
+    }
+
+    if cards.values().any(|n| *n == 4) {
+        return 5;
+    }
+
+    if cards.values().filter(|n| **n == 3).count() == 1
+        && cards.values().filter(|n| **n == 2).count() == 1
+    {
+        return 4;
+    }
+
+    if cards.values().any(|n| *n == 3) {
+        return 3;
+    }
+
+    if cards.values().filter(|n| **n == 2).count() == 2 {
+        return 2;
+    }
+
+    if cards.values().filter(|n| **n == 2).count() == 1 {
+        return 1;
+    }
+
+    0
+}
+
+fn cards_cmp(card_order: &[char; 13], lhs: char, rhs: char) -> Ordering {
+    let position = |card| card_order.iter().position(|c| *c == card).unwrap();
+    position(lhs).cmp(&position(rhs))
+}
+
+// Total winnings, given how to type a hand and the order of individual cards.
+fn winnings(hands: &[Hand], hand_type: impl Fn(&Hand) -> u64, card_order: &[char; 13]) -> usize {
+    let mut ranked: Vec<(u64, &Hand)> = hands.iter().map(|h| (hand_type(h), h)).collect();
+
+    ranked.sort_by(|(lhs_type, lhs), (rhs_type, rhs)| {
+        lhs_type.cmp(rhs_type).then_with(|| {
+            lhs.cards_ordered
+                .iter()
+                .zip(rhs.cards_ordered.iter())
+                .map(|(l, r)| cards_cmp(card_order, *l, *r))
+                .find(|o| *o != Ordering::Equal)
+                .unwrap_or(Ordering::Equal)
+        })
+    });
+
+    let mut sum = 0;
+
+    for (idx, (ty, hand)) in ranked.iter().enumerate() {
+        let rank = idx + 1;
+        let cards: String = hand.cards_ordered.iter().collect();
+        trace!("{rank} {cards} ({})", TYPE_NAMES[*ty as usize]);
+        sum += rank * hand.bid as usize;
+    }
+
+    sum
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Model = Vec<Hand>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> ParseResult<Self::Model> {
+        input.lines().map(Hand::new).collect()
+    }
+
+    fn part1(&self, hands: &Self::Model) -> usize {
+        p1::p1(hands)
+    }
+
+    fn part2(&self, hands: &Self::Model) -> usize {
+        p2::p2(hands)
+    }
+}
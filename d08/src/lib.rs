@@ -0,0 +1,122 @@
+use std::collections::HashMap;
+
+use common::{
+    parse::{block, labelled, lines, symbols, tag, word, Parsed, Parser},
+    ParseError, ParseResult, Solution,
+};
+
+mod p1;
+mod p2;
+
+pub const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+#[derive(Debug)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub fn new(c: char) -> Option<Self> {
+        match c {
+            'L' => Some(Direction::Left),
+            'R' => Some(Direction::Right),
+            _ => None,
+        }
+    }
+}
+
+type NodeName = String;
+pub struct Node {
+    name: NodeName,
+    left: NodeName,
+    right: NodeName,
+}
+
+// "AAA = (BBB, CCC)", as the node's name and the names of the nodes to its left and right. They're
+// left as slices of the input until we know the neighbours exist, to point at any that don't.
+fn names(l: &str) -> Parsed<'_, [&str; 3]> {
+    let (name, rest) = word(l)?;
+    let (left, rest) = labelled(" = (", word).parse(rest)?;
+    let (right, rest) = labelled(",", word).parse(rest)?;
+    let (_, rest) = tag(")").parse(rest)?;
+
+    Ok(([name, left, right], rest))
+}
+
+pub struct Network {
+    instructions: Vec<Direction>,
+    nodes: HashMap<NodeName, Node>,
+}
+
+impl Network {
+    // Number of steps from `start` until we reach a node satisfying `is_end`.
+    pub fn route(&self, start: &str, is_end: impl Fn(&str) -> bool) -> usize {
+        let mut current = start;
+        let mut steps = 0;
+
+        for inst in self.instructions.iter().cycle() {
+            if is_end(current) {
+                break;
+            }
+
+            // Parsing checked every node we can step to exists.
+            let node = &self.nodes[current];
+            current = match inst {
+                Direction::Left => &node.left,
+                Direction::Right => &node.right,
+            };
+
+            steps += 1;
+        }
+
+        steps
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Model = Network;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> ParseResult<Self::Model> {
+        let (instructions, rest) = block(symbols(Direction::new, "'L' or 'R'")).parse(input)?;
+        let names = lines(names).all(rest)?;
+
+        let nodes: HashMap<NodeName, Node> = names
+            .iter()
+            .map(|[name, left, right]| {
+                let node = Node {
+                    name: name.to_string(),
+                    left: left.to_string(),
+                    right: right.to_string(),
+                };
+                (node.name.clone(), node)
+            })
+            .collect();
+
+        for name in names.iter().flat_map(|[_, left, right]| [left, right]) {
+            if !nodes.contains_key(*name) {
+                return Err(ParseError::at(name, "the name of a node"));
+            }
+        }
+        if !nodes.contains_key("AAA") {
+            return Err(ParseError::after(input.trim_end(), "a node named AAA"));
+        }
+
+        Ok(Network {
+            instructions,
+            nodes,
+        })
+    }
+
+    fn part1(&self, network: &Self::Model) -> usize {
+        p1::p1(network)
+    }
+
+    fn part2(&self, network: &Self::Model) -> usize {
+        p2::p2(network)
+    }
+}
@@ -0,0 +1,97 @@
+use common::{
+    parse::{list, signed, space1, Parser},
+    ParseResult, Solution, Stream,
+};
+
+mod p1;
+mod p2;
+
+pub const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+pub struct Sequence {
+    sequences: Vec<Vec<i64>>,
+}
+
+impl Sequence {
+    pub fn new(l: &str) -> ParseResult<Self> {
+        let first = list(signed, space1).all(l)?;
+
+        let mut s = Self {
+            sequences: vec![first],
+        };
+
+        let mut d = false;
+        while !d {
+            d = s.diff();
+        }
+
+        Ok(s)
+    }
+
+    fn diff(&mut self) -> bool {
+        let mut diffed: Vec<i64> = Vec::new();
+        let last = &self.sequences.last().unwrap();
+
+        for i in 0..last.len() - 1 {
+            let (a, b) = (last[i], last[i + 1]);
+            let diff = b - a;
+            diffed.push(diff)
+        }
+        let done = diffed.iter().all(|i| *i == 0);
+
+        self.sequences.push(diffed);
+
+        done
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Model = Vec<Sequence>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, input: &str) -> ParseResult<Self::Model> {
+        input.lines().map(Sequence::new).collect()
+    }
+
+    fn part1(&self, sequences: &Self::Model) -> i64 {
+        p1::p1(sequences)
+    }
+
+    fn part2(&self, sequences: &Self::Model) -> i64 {
+        p2::p2(sequences)
+    }
+}
+
+impl Stream for Day09 {
+    type Totals1 = i64;
+    type Totals2 = i64;
+
+    fn totals1(&self) -> i64 {
+        0
+    }
+
+    fn totals2(&self) -> i64 {
+        0
+    }
+
+    fn line1(&self, sum: &mut i64, line: &str) -> ParseResult<()> {
+        *sum += Sequence::new(line)?.extrap_forward();
+        Ok(())
+    }
+
+    fn line2(&self, sum: &mut i64, line: &str) -> ParseResult<()> {
+        *sum += Sequence::new(line)?.extrap_backward();
+        Ok(())
+    }
+
+    fn answer1(&self, sum: i64) -> i64 {
+        sum
+    }
+
+    fn answer2(&self, sum: i64) -> i64 {
+        sum
+    }
+}
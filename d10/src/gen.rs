@@ -0,0 +1,181 @@
+use std::collections::HashSet;
+
+use common::{Generate, Rng, Solution};
+use grid::{Coord, Dir, Grid};
+
+use crate::{Day10, Maze};
+
+// Whether the cell at `c` is in the blob. Everything off the edge is outside.
+fn inside(blob: &Grid<bool>, c: Coord) -> bool {
+    blob.get(c).copied().unwrap_or(false)
+}
+
+// A blob outlines a single loop if no two of its cells only meet at a corner, and it has no
+// holes: everything outside it can reach the edge.
+fn outlines_loop(blob: &Grid<bool>) -> bool {
+    for row in 0..=blob.height() as i64 {
+        for col in 0..=blob.width() as i64 {
+            let [ul, ur, dl, dr] = [(-1, -1), (-1, 0), (0, -1), (0, 0)]
+                .map(|(r, c)| inside(blob, Coord::new(row + r, col + c)));
+            if ul == dr && ur == dl && ul != ur {
+                return false;
+            }
+        }
+    }
+
+    let mut seen: HashSet<Coord> = HashSet::new();
+    let mut todo: Vec<Coord> = blob
+        .coords()
+        .filter(|c| !blob[*c])
+        .filter(|c| blob.neighbors4(*c).count() < 4)
+        .collect();
+    while let Some(c) = todo.pop() {
+        if seen.insert(c) {
+            todo.extend(blob.neighbors4(c).filter(|n| !blob[*n]));
+        }
+    }
+
+    seen.len() == blob.iter().filter(|(_, b)| !**b).count()
+}
+
+fn pipe(connections: [bool; 4]) -> char {
+    // Up, right, down, left, as in `Dir::ALL`.
+    match connections {
+        [true, false, true, false] => '|',
+        [false, true, false, true] => '-',
+        [true, true, false, false] => 'L',
+        [true, false, false, true] => 'J',
+        [false, false, true, true] => '7',
+        [false, true, true, false] => 'F',
+        _ => unreachable!("Not a bend or a straight: {connections:?}"),
+    }
+}
+
+// The tiles the loop goes through, walking it from the start.
+fn walk_loop(maze: &Maze) -> Vec<Coord> {
+    let connects = |c: Coord, d: Dir| maze.nodes[c].is_some_and(|n| n.connects(d));
+
+    let mut tiles = vec![maze.start];
+    let mut dir = Dir::ALL
+        .into_iter()
+        .find(|d| connects(maze.start, *d))
+        .expect("The start is on the loop");
+    let mut at = maze.start.step(dir);
+
+    while at != maze.start {
+        tiles.push(at);
+        dir = Dir::ALL
+            .into_iter()
+            .find(|d| *d != dir.opposite() && connects(at, *d))
+            .unwrap();
+        at = at.step(dir);
+    }
+
+    tiles
+}
+
+impl Generate for Day10 {
+    // The loop is the outline of a random blob of cells, where the corners of the cells are the
+    // tiles. Tiles off the loop are random junk.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let n = size.max(2);
+        let mut blob = Grid::new(n, n, false);
+
+        let first = Coord::new(rng.index(n) as i64, rng.index(n) as i64);
+        blob[first] = true;
+
+        let target = rng.range(1..=(n * n / 2) as i64) as usize;
+        let mut cells = 1;
+        for _ in 0..n * n * 4 {
+            if cells == target {
+                break;
+            }
+
+            let c = Coord::new(rng.index(n) as i64, rng.index(n) as i64);
+            if blob[c] || !blob.neighbors4(c).any(|n| blob[n]) {
+                continue;
+            }
+
+            blob[c] = true;
+            if outlines_loop(&blob) {
+                cells += 1;
+            } else {
+                blob[c] = false;
+            }
+        }
+
+        let mut tiles = Grid::new(n + 1, n + 1, '.');
+        let mut on_loop = Vec::new();
+        for c in tiles.coords().collect::<Vec<_>>() {
+            let [ul, ur, dl, dr] = [(-1, -1), (-1, 0), (0, -1), (0, 0)]
+                .map(|(r, col)| inside(&blob, Coord::new(c.row + r, c.col + col)));
+            let connections = [ul != ur, ur != dr, dl != dr, ul != dl];
+
+            tiles[c] = if connections.contains(&true) {
+                on_loop.push(c);
+                pipe(connections)
+            } else if rng.chance(0.5) {
+                '.'
+            } else {
+                *rng.choose(&['|', '-', 'L', 'J', '7', 'F'])
+            };
+        }
+
+        // Junk next to the start could look like it connects to it, so clear it.
+        let start = *rng.choose(&on_loop);
+        for n in start.neighbors4() {
+            if tiles.in_bounds(n) && !on_loop.contains(&n) {
+                tiles[n] = '.';
+            }
+        }
+        tiles[start] = 'S';
+
+        tiles
+            .rows()
+            .map(|r| r.iter().collect::<String>() + "\n")
+            .collect()
+    }
+
+    fn reference1(input: &str) -> Option<u64> {
+        let maze = Day10.parse(input).ok()?;
+
+        Some(walk_loop(&maze).len() as u64 / 2)
+    }
+
+    // Blow each tile up to 3x3, so the gaps between pipes become paths, and flood fill from the
+    // outside. Tiles whose middles the flood can't reach are enclosed.
+    fn reference2(input: &str) -> Option<usize> {
+        let maze = Day10.parse(input).ok()?;
+        let on_loop: HashSet<Coord> = walk_loop(&maze).into_iter().collect();
+
+        let (h, w) = (maze.nodes.height(), maze.nodes.width());
+        let mut big = Grid::new(w * 3 + 2, h * 3 + 2, false);
+        for &c in &on_loop {
+            let middle = Coord::new(c.row * 3 + 2, c.col * 3 + 2);
+            big[middle] = true;
+            for d in Dir::ALL {
+                if maze.nodes[c].is_some_and(|n| n.connects(d)) {
+                    big[middle.step(d)] = true;
+                }
+            }
+        }
+
+        let mut outside = Grid::new(big.width(), big.height(), false);
+        let mut todo = vec![Coord::new(0, 0)];
+        while let Some(c) = todo.pop() {
+            if !outside[c] && !big[c] {
+                outside[c] = true;
+                todo.extend(big.neighbors4(c));
+            }
+        }
+
+        let enclosed = maze
+            .nodes
+            .coords()
+            .filter(|c| !on_loop.contains(c))
+            .filter(|c| !outside[Coord::new(c.row * 3 + 2, c.col * 3 + 2)])
+            .count();
+
+        Some(enclosed)
+    }
+}
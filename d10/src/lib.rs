@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+
+use common::{ParseError, ParseResult, Solution};
+use graph::Graph;
+use grid::{Coord, Dir, Grid};
+use tracing::{debug, trace};
+
+mod gen;
+mod p1;
+mod p2;
+
+pub const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Node {
+    // Indexed by Dir: NORTH, EAST, SOUTH, WEST
+    connections: [bool; 4],
+}
+
+impl Node {
+    pub fn new(c: char) -> Option<Self> {
+        let connections = match c {
+            '.' => return None,
+            'S' => [true, true, true, true],
+            '|' => [true, false, true, false],
+            '-' => [false, true, false, true],
+            'L' => [true, true, false, false],
+            'J' => [true, false, false, true],
+            '7' => [false, false, true, true],
+            'F' => [false, true, true, false],
+            _ => unreachable!("{c:?} was checked when parsing"),
+        };
+
+        Some(Self { connections })
+    }
+
+    pub fn connects(&self, dir: Dir) -> bool {
+        self.connections[dir.idx()]
+    }
+}
+
+pub struct Maze {
+    nodes: Grid<Option<Node>>,
+    start: Coord,
+}
+
+impl Graph for Maze {
+    type Node = Coord;
+
+    // Pipes connected to the one at `c`.
+    fn neighbors(&self, c: &Coord) -> impl IntoIterator<Item = Coord> {
+        let node = self.nodes.get(*c).copied().flatten();
+        let c = *c;
+
+        Dir::ALL
+            .into_iter()
+            .filter(move |d| node.is_some_and(|n| n.connects(*d)))
+            .map(move |d| c.step(d))
+            .filter(|n| matches!(self.nodes.get(*n), Some(Some(_))))
+    }
+}
+
+impl Maze {
+    // Distance from the start to every pipe on the loop.
+    pub fn distances(&self) -> HashMap<Coord, u64> {
+        graph::bfs(self, self.start)
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Model = Maze;
+    type Part1 = u64;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> ParseResult<Self::Model> {
+        let tiles = Grid::parse(
+            input,
+            |c| "|-LJ7F.S".contains(c).then_some(c),
+            "a pipe, '.' or 'S'",
+        )?;
+        let start = tiles
+            .position(|c| *c == 'S')
+            .ok_or_else(|| ParseError::after(input.trim_end(), "a start tile 'S'"))?;
+
+        let mut maze = Maze {
+            nodes: tiles.map(|c| Node::new(*c)),
+            start,
+        };
+
+        // The start connects to whichever of its neighbours connect back to it.
+        let mut actual_conn = [false, false, false, false];
+        for d in Dir::ALL {
+            let n = start.step(d);
+            trace!("start neighbour {d:?}: {:?}", maze.nodes.get(n));
+            actual_conn[d.idx()] = maze.neighbors(&n).into_iter().any(|c| c == start);
+        }
+
+        debug!("start connects {:?}", actual_conn);
+
+        maze.nodes[start] = Some(Node {
+            connections: actual_conn,
+        });
+
+        Ok(maze)
+    }
+
+    fn part1(&self, maze: &Self::Model) -> u64 {
+        p1::p1(maze)
+    }
+
+    fn part2(&self, maze: &Self::Model) -> usize {
+        p2::p2(maze)
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::HashSet;
+
+use common::{
+    parse::{cells, Cells, Parser},
+    ParseResult, Solution,
+};
+
+mod p1;
+mod p2;
+
+pub const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+pub fn dist(a: (i64, i64), b: (i64, i64)) -> u64 {
+    b.0.abs_diff(a.0) + b.1.abs_diff(a.1)
+}
+
+pub struct Image {
+    galaxies: Vec<(i64, i64)>,
+    rows: usize,
+    cols: usize,
+}
+
+impl Image {
+    // Sum of distances between every pair of galaxies, after each empty row and column has
+    // `expansion` more copies of itself added.
+    pub fn total_distance(&self, expansion: i64) -> u64 {
+        let rowp: HashSet<i64> = self.galaxies.iter().map(|g| g.0).collect();
+        let colp: HashSet<i64> = self.galaxies.iter().map(|g| g.1).collect();
+
+        let mut adjr: Vec<i64> = Vec::new();
+        let mut adjc: Vec<i64> = Vec::new();
+
+        let mut adj = 0;
+        for row in 0..self.rows {
+            if !rowp.contains(&(row as i64)) {
+                adj += expansion;
+            }
+
+            adjr.push(adj)
+        }
+
+        adj = 0;
+        for col in 0..self.cols {
+            if !colp.contains(&(col as i64)) {
+                adj += expansion;
+            }
+
+            adjc.push(adj)
+        }
+
+        let galaxies: Vec<(i64, i64)> = self
+            .galaxies
+            .iter()
+            .map(|&(row, col)| (row + adjr[row as usize], col + adjc[col as usize]))
+            .collect();
+
+        let mut sum = 0;
+
+        for a in galaxies.iter() {
+            for b in galaxies.iter() {
+                sum += dist(*a, *b);
+            }
+        }
+
+        sum / 2
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Model = Image;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(&self, input: &str) -> ParseResult<Self::Model> {
+        let Cells {
+            width,
+            height,
+            cells,
+        } = cells(|c| "#.".contains(c).then_some(c == '#'), "'#' or '.'").all(input)?;
+
+        let galaxies = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .filter(|(row, col)| cells[row * width + col])
+            .map(|(row, col)| (row as i64, col as i64))
+            .collect();
+
+        Ok(Image {
+            galaxies,
+            rows: height,
+            cols: width,
+        })
+    }
+
+    fn part1(&self, image: &Self::Model) -> u64 {
+        p1::p1(image)
+    }
+
+    fn part2(&self, image: &Self::Model) -> u64 {
+        p2::p2(image)
+    }
+}
@@ -1,6 +0,0 @@
-pub mod p1;
-pub mod p2;
-
-fn main() {
-    p2::p2().unwrap();
-}
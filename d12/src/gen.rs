@@ -0,0 +1,110 @@
+use common::{Generate, Rng, Solution};
+
+use crate::{Day12, Record, Spring};
+
+// Assigning every unknown spring both ways takes 2^unknowns checks, so give up past this many.
+const MAX_UNKNOWNS: usize = 18;
+
+fn groups(springs: &[Spring]) -> Vec<usize> {
+    springs
+        .split(|s| *s != Spring::Broken)
+        .map(|g| g.len())
+        .filter(|l| *l > 0)
+        .collect()
+}
+
+// Try every way of filling in the unknown springs, and count the ones that match.
+fn arrangements(springs: &[Spring], cond: &[usize]) -> Option<u64> {
+    let unknowns: Vec<usize> = (0..springs.len())
+        .filter(|i| springs[*i] == Spring::Unknown)
+        .collect();
+    if unknowns.len() > MAX_UNKNOWNS {
+        return None;
+    }
+
+    let mut springs = springs.to_vec();
+    let mut count = 0;
+    for bits in 0u32..1 << unknowns.len() {
+        for (b, i) in unknowns.iter().enumerate() {
+            springs[*i] = if bits >> b & 1 == 1 {
+                Spring::Broken
+            } else {
+                Spring::Working
+            };
+        }
+
+        if groups(&springs) == cond {
+            count += 1;
+        }
+    }
+
+    Some(count)
+}
+
+fn unfold(record: &Record) -> Record {
+    let mut springs = record.springs.clone();
+    for _ in 1..5 {
+        springs.push(Spring::Unknown);
+        springs.extend(&record.springs);
+    }
+
+    Record {
+        springs,
+        cond: record.cond.repeat(5),
+    }
+}
+
+impl Generate for Day12 {
+    // Rows of known springs with some of them hidden, so there's always at least one arrangement.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+
+        for _ in 0..size.max(1) {
+            let len = rng.range(1..=size as i64 + 4) as usize;
+            let mut springs: Vec<Spring> = (0..len)
+                .map(|_| match rng.chance(0.4) {
+                    true => Spring::Broken,
+                    false => Spring::Working,
+                })
+                .collect();
+            springs[rng.index(len)] = Spring::Broken;
+            let cond = groups(&springs);
+
+            let hidden = rng.range(0..=100) as f64 / 100.0;
+            for s in springs.iter_mut() {
+                if rng.chance(hidden) {
+                    *s = Spring::Unknown;
+                }
+            }
+
+            for s in &springs {
+                input += &s.to_string();
+            }
+            let cond: Vec<String> = cond.iter().map(|c| c.to_string()).collect();
+            input += &format!(" {}\n", cond.join(","));
+        }
+
+        input
+    }
+
+    fn reference1(input: &str) -> Option<u64> {
+        Day12
+            .parse(input)
+            .ok()?
+            .iter()
+            .map(|r| arrangements(&r.springs, &r.cond))
+            .sum()
+    }
+
+    fn reference2(input: &str) -> Option<usize> {
+        Day12
+            .parse(input)
+            .ok()?
+            .iter()
+            .map(|r| {
+                let r = unfold(r);
+                arrangements(&r.springs, &r.cond).map(|c| c as usize)
+            })
+            .sum()
+    }
+}
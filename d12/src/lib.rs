@@ -0,0 +1,74 @@
+use std::fmt::Display;
+
+use common::{
+    parse::{lines, list, space1, symbols, tag, unsigned, Parser},
+    ParseResult, Solution,
+};
+
+mod gen;
+mod p1;
+mod p2;
+
+pub const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+#[derive(PartialEq, Clone, Copy, Debug, Hash, Eq)]
+pub enum Spring {
+    Working,
+    Broken,
+    Unknown,
+}
+
+impl Spring {
+    pub fn new(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Self::Working),
+            '#' => Some(Self::Broken),
+            '?' => Some(Self::Unknown),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Spring {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Spring::Working => write!(f, "."),
+            Spring::Broken => write!(f, "#"),
+            Spring::Unknown => write!(f, "?"),
+        }
+    }
+}
+
+pub struct Record {
+    springs: Vec<Spring>,
+    cond: Vec<usize>,
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Model = Vec<Record>;
+    type Part1 = u64;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> ParseResult<Self::Model> {
+        // "???.### 1,1,3"
+        let record = |l| {
+            let (springs, l) = symbols(Spring::new, "'.', '#' or '?'").parse(l)?;
+            let (_, l) = space1(l)?;
+            let (cond, l) = list(unsigned, tag(",")).parse(l)?;
+
+            Ok((Record { springs, cond }, l))
+        };
+
+        lines(record).all(input)
+    }
+
+    fn part1(&self, records: &Self::Model) -> u64 {
+        p1::p1(records)
+    }
+
+    fn part2(&self, records: &Self::Model) -> usize {
+        p2::p2(records)
+    }
+}
@@ -1,26 +1,6 @@
-use std::{
-    collections::HashMap,
-    fs::File,
-    io::{BufRead, BufReader},
-};
-
-#[derive(PartialEq, Clone, Copy, Debug, Hash, Eq)]
-enum Spring {
-    Working,
-    Broken,
-    Unknown,
-}
+use std::collections::HashMap;
 
-impl Spring {
-    pub fn new(c: char) -> Self {
-        match c {
-            '.' => Self::Working,
-            '#' => Self::Broken,
-            '?' => Self::Unknown,
-            _ => unreachable!(),
-        }
-    }
-}
+use crate::{Record, Spring};
 
 fn eval_slow(v: &[Spring]) -> Vec<u64> {
     let mut ret: Vec<u64> = Vec::new();
@@ -46,7 +26,7 @@ fn eval_slow(v: &[Spring]) -> Vec<u64> {
     ret
 }
 
-fn eval(v: &Vec<Spring>, memo: &mut HashMap<Vec<Spring>, Vec<u64>>) -> Vec<u64> {
+fn eval(v: &[Spring], memo: &mut HashMap<Vec<Spring>, Vec<u64>>) -> Vec<u64> {
     let (last, rest) = v.split_last().unwrap();
 
     let sl = match rest.last() {
@@ -89,7 +69,7 @@ fn eval(v: &Vec<Spring>, memo: &mut HashMap<Vec<Spring>, Vec<u64>>) -> Vec<u64>
     result.clone()
 }
 
-fn h(cond: &Vec<u64>, other: &Vec<u64>) -> bool {
+fn h(cond: &[u64], other: &[u64]) -> bool {
     // dbg!(&cond);
     // dbg!(&other);
 
@@ -112,20 +92,18 @@ fn h(cond: &Vec<u64>, other: &Vec<u64>) -> bool {
 
     // dbg!(res);
 
-    return res;
+    res
 }
 
 fn solve2(
-    original: &Vec<Spring>,
+    original: &[Spring],
     current: Vec<Spring>,
     i: usize,
-    l: u64,
-    cond: &Vec<u64>,
+    cond: &[u64],
     sols: &mut u64,
     memo: &mut HashMap<Vec<Spring>, Vec<u64>>,
 ) {
     // dbg!(&current);
-    // dbg!(l);
 
     if i == original.len() {
         if eval(&current, memo) == *cond {
@@ -144,8 +122,8 @@ fn solve2(
             // dbg!(&n);
             let eval = eval(&n, memo);
             // dbg!(&eval);
-            if h(&cond, &eval) {
-                solve2(original, n, i + 1, l, cond, sols, memo);
+            if h(cond, &eval) {
+                solve2(original, n, i + 1, cond, sols, memo);
             }
         }
         Spring::Broken => {
@@ -154,8 +132,8 @@ fn solve2(
             // dbg!(&n);
             let eval = eval(&n, memo);
             // dbg!(&eval);
-            if h(&cond, &eval) {
-                solve2(original, n, i + 1, l, cond, sols, memo)
+            if h(cond, &eval) {
+                solve2(original, n, i + 1, cond, sols, memo)
             }
         }
         Spring::Unknown => {
@@ -164,8 +142,8 @@ fn solve2(
             // dbg!(&nw);
             let evalw = eval(&nw, memo);
             // dbg!(&evalw);
-            let working = if h(cond, &evalw) {
-                solve2(original, nw, i + 1, l, cond, sols, memo)
+            if h(cond, &evalw) {
+                solve2(original, nw, i + 1, cond, sols, memo)
             };
 
             let mut nb = current.clone();
@@ -174,39 +152,26 @@ fn solve2(
             let evalb = eval(&nb, memo);
             // dbg!(&evalb);
             let valid = h(cond, &evalb);
-            let broken = if valid {
-                solve2(original, nb, i + 1, l, cond, sols, memo)
+            if valid {
+                solve2(original, nb, i + 1, cond, sols, memo)
             };
         }
     }
 }
 
-pub fn p1() -> std::io::Result<()> {
-    let file = File::open("d12/src/input.txt")?;
-    let buf_reader = BufReader::new(file);
-
+pub fn p1(records: &[Record]) -> u64 {
     let mut memo: HashMap<Vec<Spring>, Vec<u64>> = HashMap::new();
 
     let mut sum = 0;
 
-    for l in buf_reader.lines() {
-        let l = l?;
-
-        let (springs_str, cond_str) = l.split_once(' ').unwrap();
-
-        let springs: Vec<Spring> = springs_str.chars().map(|c| Spring::new(c)).collect();
-        let cond: Vec<u64> = cond_str
-            .split(",")
-            .map(|s| u64::from_str_radix(s, 10).unwrap())
-            .collect();
+    for r in records {
+        let cond: Vec<u64> = r.cond.iter().map(|c| *c as u64).collect();
 
         let mut sols = 0;
 
-        solve2(&springs, Vec::new(), 0, 0, &cond, &mut sols, &mut memo);
+        solve2(&r.springs, Vec::new(), 0, &cond, &mut sols, &mut memo);
         sum += sols;
     }
 
-    println!("{sum}");
-
-    Ok(())
+    sum
 }
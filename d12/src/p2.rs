@@ -1,36 +1,7 @@
-use std::{
-    fmt::Display,
-    fs::File,
-    io::{BufRead, BufReader},
-};
+use std::{collections::HashMap, fmt::Display};
 
-#[derive(PartialEq, Clone, Copy, Debug, Hash, Eq)]
-enum Spring {
-    Working,
-    Broken,
-    Unknown,
-}
-
-impl Spring {
-    pub fn new(c: char) -> Self {
-        match c {
-            '.' => Self::Working,
-            '#' => Self::Broken,
-            '?' => Self::Unknown,
-            _ => unreachable!(),
-        }
-    }
-}
-
-impl Display for Spring {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Spring::Working => write!(f, "."),
-            Spring::Broken => write!(f, "#"),
-            Spring::Unknown => write!(f, "?"),
-        }
-    }
-}
+use crate::{Record, Spring};
+use tracing::{debug, trace};
 
 #[derive(Clone, Hash, PartialEq, Eq)]
 struct SolveInput {
@@ -43,7 +14,7 @@ struct SolveInput {
 
 impl SolveInput {
     pub fn exit_group(&self) -> Option<Self> {
-        println!("EXIT");
+        trace!("EXIT");
         match self.current {
             Some(cgroup) => {
                 if cgroup != *self.remaining.last().unwrap() {
@@ -61,7 +32,7 @@ impl SolveInput {
     }
 
     pub fn inc_group(&self) -> Option<Self> {
-        println!("INC");
+        trace!("INC");
         match self.current {
             Some(cgroup) => {
                 if cgroup == *self.remaining.last().unwrap() {
@@ -78,11 +49,11 @@ impl SolveInput {
     }
 
     pub fn enter_group(&self) -> Option<Self> {
-        println!("ENTER");
+        trace!("ENTER");
         match self.current {
             Some(_) => unreachable!(),
             None => {
-                if self.remaining.len() == 0 {
+                if self.remaining.is_empty() {
                     return None;
                 }
                 let mut c = self.clone();
@@ -119,37 +90,25 @@ impl Display for SolveInput {
     }
 }
 
-fn solve(original: &Vec<Spring>, input: SolveInput) -> usize {
-    println!("{input}");
+fn solve(original: &[Spring], input: SolveInput, memo: &mut HashMap<SolveInput, usize>) -> usize {
+    if let Some(sols) = memo.get(&input) {
+        return *sols;
+    }
+
+    trace!("{input}");
 
     if input.pos == original.len() {
-        // let sols = match (input.remaining.last(), input.current) {
-        //     (Some(remaining), Some(current)) => {
-        //         if input.remaining.len() == 1 && *remaining == current {
-        //             println!("SOLUTION");
-        //             1
-        //         } else {
-        //             println!("BRANCH FAIL");
-        //             0
-        //         }
-        //     }
-        //     _ => {
-        //         println!("BRANCH FAIL");
-        //         0
-        //     }
-        // };
-
         let input = if input.is_in_group() {
             input.exit_group()
         } else {
             Some(input)
         };
 
-        let sols = if input.is_some() && input.unwrap().remaining.len() == 0 {
-            println!("SOLUTION");
+        let sols = if input.is_some() && input.unwrap().remaining.is_empty() {
+            trace!("SOLUTION");
             1
         } else {
-            println!("BRANCH FAIL");
+            trace!("BRANCH FAIL");
             0
         };
 
@@ -157,119 +116,114 @@ fn solve(original: &Vec<Spring>, input: SolveInput) -> usize {
     };
 
     let next = original[input.pos];
-    println!("{next}");
+    trace!("{next}");
 
     let sols = match (next, input.is_in_group()) {
         (Spring::Working, true) => {
             let n = input.exit_group();
             match n {
-                Some(n) => solve(&original, n),
+                Some(n) => solve(original, n, memo),
                 None => 0,
             }
         }
         (Spring::Working, false) => {
             let mut n = input.clone();
             n.pos += 1;
-            solve(original, n)
+            solve(original, n, memo)
         }
         (Spring::Broken, true) => {
             let n = input.inc_group();
             match n {
-                Some(n) => solve(&original, n),
+                Some(n) => solve(original, n, memo),
                 None => 0,
             }
         }
         (Spring::Broken, false) => {
             let n = input.enter_group();
             match n {
-                Some(n) => solve(&original, n),
+                Some(n) => solve(original, n, memo),
                 None => 0,
             }
         }
         (Spring::Unknown, true) => {
-            println!("WORKING: {}", input.pos);
+            trace!("WORKING: {}", input.pos);
             let working_input = input.exit_group();
             let working = match working_input {
-                Some(n) => solve(original, n),
+                Some(n) => solve(original, n, memo),
                 None => 0,
             };
 
-            println!("BROKEN: {}", input.pos);
+            trace!("BROKEN: {}", input.pos);
             let broken_input = input.inc_group();
             let broken = match broken_input {
-                Some(n) => solve(original, n),
+                Some(n) => solve(original, n, memo),
                 None => 0,
             };
 
             working + broken
         }
         (Spring::Unknown, false) => {
-            println!("BROKEN: {}", input.pos);
+            trace!("BROKEN: {}", input.pos);
             let broken_input = input.enter_group();
             let broken = match broken_input {
-                Some(n) => solve(original, n),
+                Some(n) => solve(original, n, memo),
                 None => 0,
             };
 
-            println!("WORKING: {}", input.pos);
+            trace!("WORKING: {}", input.pos);
             let mut working_input = input.clone();
             working_input.pos += 1;
-            let working = solve(original, working_input);
+            let working = solve(original, working_input, memo);
 
             working + broken
         }
     };
 
+    memo.insert(input, sols);
+
     sols
 }
 
-pub fn p2() -> std::io::Result<()> {
-    let file = File::open("d12/src/input.txt")?;
-    let buf_reader = BufReader::new(file);
-
-    let mut sum = 0;
+// Each record is really five copies of itself, joined by unknown springs.
+fn unfold(record: &Record) -> Record {
+    let mut springs: Vec<Spring> = Vec::new();
+    for i in 0..5 {
+        if i > 0 {
+            springs.push(Spring::Unknown);
+        }
+        springs.extend(&record.springs);
+    }
 
-    for (idx, l) in buf_reader.lines().enumerate() {
-        let l = l?;
+    let len = record.cond.len();
+    let cond: Vec<usize> = record.cond.iter().copied().cycle().take(len * 5).collect();
 
-        let (springs_str, cond_str) = l.split_once(' ').unwrap();
+    Record { springs, cond }
+}
 
-        let springs: Vec<Spring> = springs_str.chars().map(|c| Spring::new(c)).collect();
-        // let mut springs_d: Vec<Spring> = Vec::new();
-        // springs_d.extend(&springs);
-        // springs_d.push(Spring::Unknown);
-        // springs_d.extend(&springs);
-        // springs_d.push(Spring::Unknown);
-        // springs_d.extend(&springs);
-        // springs_d.push(Spring::Unknown);
-        // springs_d.extend(&springs);
-        // springs_d.push(Spring::Unknown);
-        // springs_d.extend(&springs);
+pub fn p2(records: &[Record]) -> usize {
+    let mut sum = 0;
 
-        let mut cond: Vec<usize> = cond_str
-            .split(",")
-            .map(|s| usize::from_str_radix(s, 10).unwrap())
-            .collect();
+    for (idx, r) in records.iter().enumerate() {
+        let r = unfold(r);
 
-        // let len = cond.len();
-        // let mut cond: Vec<usize> = cond.into_iter().cycle().take(len * 5).collect();
+        let mut cond = r.cond;
         cond.reverse();
 
+        let mut memo: HashMap<SolveInput, usize> = HashMap::new();
+
         let res = solve(
-            &springs,
+            &r.springs,
             SolveInput {
                 pos: 0,
                 current: None,
                 remaining: cond,
             },
+            &mut memo,
         );
         sum += res;
 
-        println!("Completed {idx}. Result: {res}");
-        println!("------------------------------")
+        debug!("Completed {idx}. Result: {res}");
     }
 
-    println!("{sum}");
-
-    Ok(())
+    sum
 }
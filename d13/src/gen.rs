@@ -0,0 +1,137 @@
+use std::collections::HashSet;
+
+use common::{Generate, Rng, Solution};
+use grid::{Coord, Grid};
+
+use crate::Day13;
+
+// A line of reflection, as how much it adds to the summary: 100 times the rows above it, or the
+// columns left of it.
+fn reflections(g: &Grid<bool>) -> HashSet<usize> {
+    let mirrors = |g: &Grid<bool>, between: usize| {
+        (0..between.min(g.height() - between)).all(|i| g.row(between - 1 - i) == g.row(between + i))
+    };
+
+    let rows = (1..g.height()).filter(|r| mirrors(g, *r)).map(|r| r * 100);
+    let t = g.transpose();
+    let cols = (1..t.height()).filter(|c| mirrors(&t, *c));
+
+    rows.chain(cols).collect()
+}
+
+// The pattern's line of reflection, and the different one it has once its smudge is cleaned off.
+// `None` unless there's exactly one of each.
+fn lines(g: &Grid<bool>) -> Option<(usize, usize)> {
+    let old = reflections(g);
+    if old.len() != 1 {
+        return None;
+    }
+
+    let mut new = HashSet::new();
+    let mut g = g.clone();
+    for c in g.coords().collect::<Vec<_>>() {
+        g[c] = !g[c];
+        new.extend(reflections(&g).difference(&old));
+        g[c] = !g[c];
+    }
+    if new.len() != 1 {
+        return None;
+    }
+
+    Some((*old.iter().next()?, *new.iter().next()?))
+}
+
+fn find(classes: &mut [usize], i: usize) -> usize {
+    if classes[i] != i {
+        classes[i] = find(classes, classes[i]);
+    }
+    classes[i]
+}
+
+// Pairs of rows that mirror each other across the line below row `above - 1`.
+fn mirrored(height: usize, above: usize) -> impl Iterator<Item = (usize, usize)> {
+    (0..above.min(height - above)).map(move |i| (above - 1 - i, above + i))
+}
+
+fn pattern(rng: &mut Rng, size: usize) -> Grid<bool> {
+    loop {
+        let height = rng.range(3..=size as i64 + 5) as usize;
+        let width = rng.range(2..=size as i64 + 5) as usize;
+
+        // Make the rows reflect across two lines, then smudge a row only the second one mirrors.
+        let old = rng.range(1..=height as i64 - 1) as usize;
+        let new = rng.range(1..=height as i64 - 1) as usize;
+        let kept: HashSet<usize> = mirrored(height, old).flat_map(|(a, b)| [a, b]).collect();
+        let smudgeable: Vec<usize> = mirrored(height, new)
+            .flat_map(|(a, b)| [a, b])
+            .filter(|r| !kept.contains(r))
+            .collect();
+        if old == new || smudgeable.is_empty() {
+            continue;
+        }
+
+        let mut classes: Vec<usize> = (0..height).collect();
+        for (a, b) in mirrored(height, old).chain(mirrored(height, new)) {
+            let (a, b) = (find(&mut classes, a), find(&mut classes, b));
+            classes[a] = b;
+        }
+
+        let rows: Vec<Vec<bool>> = (0..height)
+            .map(|_| (0..width).map(|_| rng.chance(0.5)).collect())
+            .collect();
+        let mut g = Grid::new(width, height, false);
+        for c in g.coords().collect::<Vec<_>>() {
+            g[c] = rows[find(&mut classes, c.row as usize)][c.col as usize];
+        }
+
+        let smudge = Coord::new(*rng.choose(&smudgeable) as i64, rng.index(width) as i64);
+        g[smudge] = !g[smudge];
+
+        if rng.chance(0.5) {
+            g = g.transpose();
+        }
+
+        // Random rows can reflect in more places than planned, so check.
+        if lines(&g).is_some() {
+            return g;
+        }
+    }
+}
+
+fn parse(input: &str) -> Option<Vec<Grid<bool>>> {
+    // Parsing with the solution checks the input is well formed.
+    Day13.parse(input).ok()?;
+
+    input
+        .split("\n\n")
+        .map(|b| Grid::parse(b, |c| Some(c == '#'), "'#' or '.'").ok())
+        .collect()
+}
+
+impl Generate for Day13 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                pattern(rng, size)
+                    .rows()
+                    .map(|r| {
+                        r.iter()
+                            .map(|b| if *b { '#' } else { '.' })
+                            .collect::<String>()
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n")
+            + "\n"
+    }
+
+    fn reference1(input: &str) -> Option<usize> {
+        parse(input)?.iter().map(|g| lines(g).map(|l| l.0)).sum()
+    }
+
+    fn reference2(input: &str) -> Option<usize> {
+        parse(input)?.iter().map(|g| lines(g).map(|l| l.1)).sum()
+    }
+}
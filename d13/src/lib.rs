@@ -0,0 +1,83 @@
+use std::{collections::HashMap, fmt::Display};
+
+use common::{ParseResult, Solution};
+use grid::Grid;
+
+mod gen;
+mod p1;
+mod p2;
+
+pub const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+// Every distinct line, and the indices it appears at.
+type Buckets = HashMap<Vec<char>, Vec<usize>>;
+
+fn buckets(lines: &[Vec<char>]) -> Buckets {
+    let mut b: Buckets = HashMap::new();
+    for (idx, r) in lines.iter().enumerate() {
+        b.entry(r.clone()).or_default().push(idx);
+    }
+
+    b
+}
+
+pub struct Pattern {
+    rows: Vec<Vec<char>>,
+    cols: Vec<Vec<char>>,
+    rowb: Buckets,
+    colb: Buckets,
+}
+
+impl Pattern {
+    pub fn new(block: &str) -> ParseResult<Self> {
+        let grid = Grid::parse(block, |c| "#.".contains(c).then_some(c), "'#' or '.'")?;
+
+        // Columns are just the rows of the transposed pattern.
+        let rows: Vec<Vec<char>> = grid.rows().map(|r| r.to_vec()).collect();
+        let cols: Vec<Vec<char>> = grid.transpose().rows().map(|r| r.to_vec()).collect();
+
+        let rowb = buckets(&rows);
+        let colb = buckets(&cols);
+
+        Ok(Self {
+            rows,
+            cols,
+            rowb,
+            colb,
+        })
+    }
+}
+
+impl Display for Pattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for r in &self.rows {
+            writeln!(f, "{}", r.iter().collect::<String>())?;
+        }
+
+        Ok(())
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Model = Vec<Pattern>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> ParseResult<Self::Model> {
+        input
+            .split("\n\n")
+            .filter(|b| !b.trim().is_empty())
+            .map(Pattern::new)
+            .collect()
+    }
+
+    fn part1(&self, patterns: &Self::Model) -> usize {
+        p1::p1(patterns)
+    }
+
+    fn part2(&self, patterns: &Self::Model) -> usize {
+        p2::p2(patterns)
+    }
+}
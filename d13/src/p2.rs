@@ -1,30 +1,27 @@
-use std::{
-    collections::HashMap,
-    fs::File,
-    io::{BufRead, BufReader},
-    iter::zip,
-};
+use std::{collections::HashMap, iter::zip};
+
+use tracing::{debug, trace};
+
+use crate::Pattern;
 
 fn refl_inner(
     b: &HashMap<Vec<char>, Vec<usize>>,
-    d: &Vec<Vec<char>>,
+    d: &[Vec<char>],
     leven: &HashMap<Vec<char>, Vec<Vec<char>>>,
     i: usize,
 ) -> bool {
-    dbg!(i);
+    trace!(i, "trying reflection");
 
     let mut l = i;
     let mut r = i + 1;
 
     let mut swap_used = false;
 
-    while l >= 0 && r < d.len() {
-        dbg!(l);
-        dbg!(r);
+    while r < d.len() {
         let v = &d[l];
         let occ = &b[v];
 
-        dbg!(&occ);
+        trace!(l, r, "occurrences {occ:?}");
 
         if !(occ.contains(&l) && occ.contains(&r)) {
             if swap_used {
@@ -34,25 +31,25 @@ fn refl_inner(
             if !swap_used {
                 let cands = leven.get(v).unwrap();
                 for c in cands {
-                    println!(
+                    trace!(
                         "SWAP:\n{}\n{}",
                         &v.iter().collect::<String>(),
                         &c.iter().collect::<String>()
                     );
                     let occ_c = &b[c];
-                    dbg!(occ_c);
+                    trace!("occurrences {occ_c:?}");
                     if !(occ_c.contains(&r)) {
-                        println!("SWAP FAILED");
+                        trace!("SWAP FAILED");
                         continue;
                     };
-                    println!("USED");
+                    trace!("USED");
                     swap_used = true;
                     break;
                 }
             }
 
             if !swap_used {
-                println!("SWAP REALLY FAILED");
+                trace!("SWAP REALLY FAILED");
                 return false;
             }
         }
@@ -69,113 +66,47 @@ fn refl_inner(
 
 fn refl(
     b: &HashMap<Vec<char>, Vec<usize>>,
-    d: &Vec<Vec<char>>,
+    d: &[Vec<char>],
     leven: &HashMap<Vec<char>, Vec<Vec<char>>>,
 ) -> Option<usize> {
-    for i in 0..d.len() - 1 {
-        if refl_inner(b, d, leven, i) {
-            return Some(i);
-        }
-    }
-
-    None
+    (0..d.len() - 1).find(|&i| refl_inner(b, d, leven, i))
 }
 
-fn pattern(reader: &mut BufReader<File>) -> Option<usize> {
-    let mut rows: Vec<Vec<char>> = Vec::new();
-    let mut cols: Vec<Vec<char>> = Vec::new();
-
-    for (idx, l) in reader
-        .lines()
-        .take_while(|l| !l.as_ref().unwrap().is_empty())
-        .enumerate()
-    {
-        let l = l.unwrap();
-        rows.push(l.chars().collect());
-
-        for (idx, c) in l.chars().enumerate() {
-            if idx >= cols.len() {
-                cols.push(Vec::new());
+// For every distinct line, the other lines exactly one smudge away from it.
+fn smudges(b: &HashMap<Vec<char>, Vec<usize>>) -> HashMap<Vec<char>, Vec<Vec<char>>> {
+    let mut d: HashMap<Vec<char>, Vec<Vec<char>>> = HashMap::new();
+    for a in b.keys() {
+        d.insert(a.clone(), Vec::new());
+        for b in b.keys() {
+            if leven(a, b) {
+                d.get_mut(a).unwrap().push(b.clone());
             }
-
-            cols[idx].push(c);
-        }
-    }
-
-    if rows.len() == 0 {
-        return None;
-    }
-
-    let mut rowb: HashMap<Vec<char>, Vec<usize>> = HashMap::new();
-    for (idx, r) in rows.iter().enumerate() {
-        if !rowb.contains_key(r) {
-            rowb.insert(r.clone(), Vec::new());
         }
-
-        rowb.get_mut(r).unwrap().push(idx);
     }
 
-    let mut colb: HashMap<Vec<char>, Vec<usize>> = HashMap::new();
-    for (idx, r) in cols.iter().enumerate() {
-        if !colb.contains_key(r) {
-            colb.insert(r.clone(), Vec::new());
-        }
-
-        colb.get_mut(r).unwrap().push(idx);
-    }
-
-    let mut rowd: HashMap<Vec<char>, Vec<Vec<char>>> = HashMap::new();
-    for a in rowb.keys() {
-        rowd.insert(a.clone(), Vec::new());
-        for b in rowb.keys() {
-            if leven(&a, &b) {
-                rowd.get_mut(a).unwrap().push(b.clone());
-            }
-        }
-    }
+    d
+}
 
-    let mut cold: HashMap<Vec<char>, Vec<Vec<char>>> = HashMap::new();
-    for a in colb.keys() {
-        cold.insert(a.clone(), Vec::new());
-        for b in colb.keys() {
-            if leven(&a, &b) {
-                cold.get_mut(a).unwrap().push(b.clone());
-            }
-        }
-    }
+fn summarize(p: &Pattern) -> usize {
+    let rowd = smudges(&p.rowb);
+    let cold = smudges(&p.colb);
 
     let mut sum = 0;
 
-    let mut added = false;
-
-    match refl(&rowb, &rows, &rowd) {
-        Some(r) => {
-            println!("row: {r}");
-            added = true;
-            sum += 100 * (r + 1)
-        }
-        None => {}
+    if let Some(r) = refl(&p.rowb, &p.rows, &rowd) {
+        debug!("row: {r}");
+        sum += 100 * (r + 1)
+    } else if let Some(r) = refl(&p.colb, &p.cols, &cold) {
+        debug!("col: {r}");
+        sum += r + 1
     }
 
-    if !added {
-        match refl(&colb, &cols, &cold) {
-            Some(r) => {
-                println!("col: {r}");
-                added = true;
-                sum += r + 1
-            }
-            None => {}
-        }
-    }
-
-    for r in &rows {
-        println!("{}", r.iter().collect::<String>());
-    }
+    trace!("pattern:\n{p}");
 
-    return Some(sum);
+    sum
 }
 
-fn leven(a: &Vec<char>, b: &Vec<char>) -> bool {
+fn leven(a: &[char], b: &[char]) -> bool {
     let mut swap_used = false;
 
     for (ca, cb) in zip(a.iter(), b.iter()) {
@@ -191,17 +122,6 @@ fn leven(a: &Vec<char>, b: &Vec<char>) -> bool {
     swap_used
 }
 
-pub fn p2() -> std::io::Result<()> {
-    let file = File::open("d13/src/input.txt")?;
-    let mut buf_reader = BufReader::new(file);
-
-    let mut sum = 0;
-    while let Some(s) = pattern(&mut buf_reader) {
-        sum += s;
-        println!("");
-    }
-
-    println!("{sum}");
-
-    Ok(())
+pub fn p2(patterns: &[Pattern]) -> usize {
+    patterns.iter().map(summarize).sum()
 }
@@ -0,0 +1,67 @@
+use common::{ParseResult, Solution};
+use grid::{Coord, Grid};
+
+mod p1;
+mod p2;
+
+pub const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Platform {
+    b: Grid<char>,
+}
+
+impl Platform {
+    // Roll every rock as far north as it will go. Other directions are done by rotating the
+    // platform first.
+    pub fn north(&mut self) {
+        for col in 0..self.b.width() as i64 {
+            // Where the next rock in this column will come to rest.
+            let mut next_free = 0;
+
+            for row in 0..self.b.height() as i64 {
+                let c = Coord::new(row, col);
+                match self.b[c] {
+                    '#' => next_free = row + 1,
+                    'O' => {
+                        self.b[c] = '.';
+                        self.b[Coord::new(next_free, col)] = 'O';
+                        next_free += 1;
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    // Total load on the north support beams.
+    pub fn load(&self) -> usize {
+        self.b
+            .iter()
+            .filter(|(_, c)| **c == 'O')
+            .map(|(c, _)| self.b.height() - c.row as usize)
+            .sum()
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Model = Platform;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> ParseResult<Self::Model> {
+        Ok(Platform {
+            b: Grid::parse(input, |c| "O#.".contains(c).then_some(c), "'O', '#' or '.'")?,
+        })
+    }
+
+    fn part1(&self, platform: &Self::Model) -> usize {
+        p1::p1(platform)
+    }
+
+    fn part2(&self, platform: &Self::Model) -> usize {
+        p2::p2(platform)
+    }
+}
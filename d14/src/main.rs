@@ -1,5 +0,0 @@
-pub mod p1;
-
-fn main() {
-    p1::p1().unwrap();
-}
@@ -0,0 +1,57 @@
+use crate::Platform;
+use cycle::Cycle;
+use grid::render::{Frames, Picture};
+use tracing::debug;
+
+const CYCLES: usize = 1000000000;
+
+impl Platform {
+    // Tilt north, west, south and east. Rotating clockwise brings each of those to the top in turn,
+    // and four rotations leave us facing north again.
+    fn spin(&mut self) {
+        for _ in 0..4 {
+            self.north();
+            self.b = self.b.rotate_cw();
+        }
+    }
+
+    // Like `spin`, but with a frame after every tilt.
+    fn spin_frames(&mut self, frames: &mut Frames) {
+        for turns in 0..4 {
+            self.north();
+
+            // Turn the picture back so north is always up.
+            let mut b = self.b.clone();
+            for _ in 0..turns {
+                b = b.rotate_ccw();
+            }
+            frames.push(&Picture::of(&b, |c| match c {
+                'O' => [230, 140, 30],
+                '#' => [90, 90, 90],
+                _ => [0, 0, 0],
+            }));
+
+            self.b = self.b.rotate_cw();
+        }
+    }
+}
+
+pub fn p2(platform: &Platform) -> usize {
+    // The platform eventually settles into a loop, so find it and skip ahead.
+    let cycle = Cycle::find(platform.clone(), |p| {
+        let mut p = p.clone();
+        p.spin();
+        p
+    });
+    debug!("loops every {} cycles after {}", cycle.period, cycle.prefix);
+
+    if let Some(mut frames) = Frames::new("d14-spin") {
+        // Once round the loop is enough to see everything that happens.
+        let mut p = platform.clone();
+        for _ in 0..cycle.prefix + cycle.period {
+            p.spin_frames(&mut frames);
+        }
+    }
+
+    cycle.nth(CYCLES).load()
+}
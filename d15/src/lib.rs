@@ -0,0 +1,76 @@
+use common::{
+    parse::{block, list, tag, unsigned, word, Parser},
+    ParseError, ParseResult, Solution,
+};
+
+mod p1;
+mod p2;
+
+pub const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+pub fn hash(input: &str) -> usize {
+    let mut val: usize = 0;
+    for c in input.chars() {
+        val += c as usize;
+        val *= 17;
+        val %= 256;
+    }
+
+    val
+}
+
+/// What a step does to the lens labelled with it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Remove,
+    /// Put in a lens of this focal length, replacing any with the same label.
+    Insert(u64),
+}
+
+#[derive(Debug)]
+pub struct Step {
+    // The step as written, which is what part 1 hashes.
+    text: String,
+    label: String,
+    op: Op,
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Model = Vec<Step>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> ParseResult<Self::Model> {
+        // "rn=1" or "cm-".
+        let step = |s| {
+            let (label, rest) = word(s)?;
+            let (op, rest) = if let Some(rest) = rest.strip_prefix('-') {
+                (Op::Remove, rest)
+            } else if let Some(focal) = rest.strip_prefix('=') {
+                let (focal, rest) = unsigned(focal)?;
+                (Op::Insert(focal), rest)
+            } else {
+                return Err(ParseError::at(rest, "'-' or '='"));
+            };
+
+            let step = Step {
+                text: s[..s.len() - rest.len()].to_string(),
+                label: label.to_string(),
+                op,
+            };
+            Ok((step, rest))
+        };
+
+        block(list(step, tag(","))).all(input)
+    }
+
+    fn part1(&self, steps: &Self::Model) -> usize {
+        p1::p1(steps)
+    }
+
+    fn part2(&self, steps: &Self::Model) -> usize {
+        p2::p2(steps)
+    }
+}
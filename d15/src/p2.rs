@@ -1,54 +1,20 @@
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-};
+use crate::{hash, Op, Step};
 
-pub fn hash(input: &str) -> usize {
-    let mut val: usize = 0;
-    for c in input.chars() {
-        val += c as usize;
-        val *= 17;
-        val = val % 256;
-    }
-
-    val
-}
-
-pub fn p2() -> std::io::Result<()> {
-    let file = File::open("d15/src/input.txt")?;
-    let buf_reader = BufReader::new(file);
-
-    let mut hmap: Vec<Vec<(String, u64)>> = Vec::new();
-    for _ in 0..256 {
-        hmap.push(Vec::new());
-    }
+pub fn p2(steps: &[Step]) -> usize {
+    let mut hmap: Vec<Vec<(&str, u64)>> = vec![Vec::new(); 256];
 
-    let file = File::open("d15/src/input.txt")?;
-    let mut buf_reader = BufReader::new(file);
+    for step in steps {
+        let label = step.label.as_str();
+        let bucket = &mut hmap[hash(label)];
+        let idx = bucket.iter().position(|(s, _)| *s == label);
 
-    let mut s: String = String::new();
-    buf_reader.read_line(&mut s);
-
-    for step in s.split(',') {
-        if step.contains('-') {
-            let (label, _) = step.split_at(step.len() - 1);
-            let h = hash(&label);
-            let bucket = hmap.get_mut(h).unwrap();
-            if let Some(idx) = bucket.iter().position(|(s, _)| s == label) {
+        match (step.op, idx) {
+            (Op::Remove, Some(idx)) => {
                 bucket.remove(idx);
             }
-        }
-        if step.contains("=") {
-            let (label, flength) = step.split_once('=').unwrap();
-            let flength = u64::from_str_radix(flength, 10).unwrap();
-
-            let h = hash(&label);
-            let bucket = hmap.get_mut(h).unwrap();
-            let idx = bucket.iter().position(|(s, _)| s == label);
-            match idx {
-                Some(idx) => *bucket.get_mut(idx).unwrap() = (label.to_string(), flength),
-                None => bucket.push((label.to_string(), flength)),
-            }
+            (Op::Remove, None) => {}
+            (Op::Insert(flength), Some(idx)) => bucket[idx] = (label, flength),
+            (Op::Insert(flength), None) => bucket.push((label, flength)),
         }
     }
 
@@ -56,11 +22,9 @@ pub fn p2() -> std::io::Result<()> {
 
     for (idx, b) in hmap.iter().enumerate() {
         for (lidx, l) in b.iter().enumerate() {
-            sum += ((1 + idx) * (lidx + 1) * l.1 as usize);
+            sum += (1 + idx) * (lidx + 1) * l.1 as usize;
         }
     }
 
-    println!("{sum}");
-
-    Ok(())
+    sum
 }
@@ -0,0 +1,120 @@
+use std::collections::HashSet;
+
+use common::{ParseResult, Solution};
+use grid::{
+    render::{Frames, Picture},
+    Coord, Dir, Grid,
+};
+
+mod p1;
+mod p2;
+
+pub const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+pub struct Contraption {
+    b: Grid<char>,
+}
+
+impl Contraption {
+    fn step(&self, p: Coord, dir: Dir) -> Option<Coord> {
+        let n = p.step(dir);
+        if self.b.in_bounds(n) {
+            Some(n)
+        } else {
+            None
+        }
+    }
+
+    // The directions a beam heading in `dir` leaves the tile at `p` in.
+    fn bounce(&self, p: Coord, dir: Dir) -> [Option<Dir>; 2] {
+        match self.b[p] {
+            '/' => [
+                Some(match dir {
+                    Dir::Right => Dir::Up,
+                    Dir::Left => Dir::Down,
+                    Dir::Down => Dir::Left,
+                    Dir::Up => Dir::Right,
+                }),
+                None,
+            ],
+            '\\' => [
+                Some(match dir {
+                    Dir::Right => Dir::Down,
+                    Dir::Left => Dir::Up,
+                    Dir::Down => Dir::Right,
+                    Dir::Up => Dir::Left,
+                }),
+                None,
+            ],
+            // Splitters are passed straight through along their own axis.
+            c @ ('|' | '-') if dir.is_vertical() != (c == '|') => {
+                [Some(dir.turn_left()), Some(dir.turn_right())]
+            }
+            _ => [Some(dir), None],
+        }
+    }
+
+    fn picture(&self, seen: &HashSet<(Coord, Dir)>, heads: &[(Coord, Dir)]) -> Picture {
+        let mut picture = Picture::of(&self.b, |c| match c {
+            '.' => [0, 0, 0],
+            _ => [90, 90, 90],
+        });
+        picture.paint(seen.iter().map(|t| t.0), [140, 110, 20]);
+        picture.paint(heads.iter().map(|t| t.0), [255, 240, 120]);
+
+        picture
+    }
+
+    // Number of tiles energized by a beam entering at `location` heading in `dir`. The beams are
+    // followed a step at a time, adding a frame to `frames` after each.
+    pub fn energized(&self, location: Coord, dir: Dir, mut frames: Option<&mut Frames>) -> usize {
+        let mut seen: HashSet<(Coord, Dir)> = HashSet::from([(location, dir)]);
+        let mut heads = vec![(location, dir)];
+
+        while !heads.is_empty() {
+            if let Some(frames) = frames.as_deref_mut() {
+                frames.push(&self.picture(&seen, &heads));
+            }
+
+            heads = heads
+                .iter()
+                .flat_map(|&(p, d)| {
+                    self.bounce(p, d)
+                        .into_iter()
+                        .flatten()
+                        .filter_map(move |d| Some((self.step(p, d)?, d)))
+                })
+                .filter(|h| seen.insert(*h))
+                .collect();
+        }
+
+        let h: HashSet<Coord> = seen.iter().map(|t| t.0).collect();
+        h.len()
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Model = Contraption;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> ParseResult<Self::Model> {
+        Ok(Contraption {
+            b: Grid::parse(
+                input,
+                |c| "./\\|-".contains(c).then_some(c),
+                "'.', a mirror or a splitter",
+            )?,
+        })
+    }
+
+    fn part1(&self, contraption: &Self::Model) -> usize {
+        p1::p1(contraption)
+    }
+
+    fn part2(&self, contraption: &Self::Model) -> usize {
+        p2::p2(contraption)
+    }
+}
@@ -0,0 +1,101 @@
+use common::{ParseResult, Solution};
+use grid::{
+    render::{Frames, Picture},
+    Coord, Dir, Grid,
+};
+use tracing::debug;
+
+mod p1;
+mod p2;
+
+pub const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+pub struct Board {
+    b: Grid<u32>,
+}
+
+impl Board {
+    pub fn cost(&self, coord: &Coord) -> u32 {
+        self.b[*coord]
+    }
+
+    pub fn target(&self) -> Coord {
+        Coord::new(self.b.height() as i64 - 1, self.b.width() as i64 - 1)
+    }
+
+    pub fn step(&self, coord: Coord, dir: Dir) -> Option<Coord> {
+        let n = coord.step(dir);
+        if self.b.in_bounds(n) {
+            Some(n)
+        } else {
+            None
+        }
+    }
+}
+
+// States the search settles between frames.
+const FRAME_EVERY: usize = 5000;
+
+// Frames of the search spreading out from the start, for rendering.
+pub struct Frontier<'a> {
+    board: &'a Board,
+    frames: Frames,
+    reached: Vec<Coord>,
+}
+
+impl<'a> Frontier<'a> {
+    pub fn new(board: &'a Board, name: &str) -> Option<Self> {
+        Some(Self {
+            board,
+            frames: Frames::new(name)?,
+            reached: Vec::new(),
+        })
+    }
+
+    // Blocks darker the more heat they lose, the search in blue and the path in red.
+    fn picture(&self, path: &[Coord]) -> Picture {
+        let mut picture = Picture::of(&self.board.b, |cost| [(*cost * 20) as u8; 3]);
+        picture.paint(self.reached.iter().copied(), [40, 80, 220]);
+        picture.paint(path.iter().copied(), [230, 40, 40]);
+
+        picture
+    }
+
+    // The search has found the cheapest way to a state at `c`.
+    pub fn settle(&mut self, c: Coord) {
+        self.reached.push(c);
+
+        if self.reached.len().is_multiple_of(FRAME_EVERY) {
+            self.frames.push(&self.picture(&[]));
+        }
+    }
+
+    pub fn finish(mut self, path: &[Coord]) {
+        let picture = self.picture(path);
+        self.frames.push(&picture);
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Model = Board;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(&self, input: &str) -> ParseResult<Self::Model> {
+        let b = Grid::parse(input, |c| c.to_digit(10), "a digit")?;
+
+        debug!("{}, {}", b.width(), b.height());
+
+        Ok(Board { b })
+    }
+
+    fn part1(&self, board: &Self::Model) -> u32 {
+        p1::p1(board)
+    }
+
+    fn part2(&self, board: &Self::Model) -> u32 {
+        p2::p2(board)
+    }
+}
@@ -1,67 +1,8 @@
-use std::{
-    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
-    fs::File,
-    io::{BufRead, BufReader},
-};
+use graph::WeightedGraph;
+use grid::{Coord, Dir};
+use tracing::debug;
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-pub struct Coord {
-    row: i64,
-    col: i64,
-}
-
-impl Coord {
-    pub fn new(row: i64, col: i64) -> Self {
-        Self { row, col }
-    }
-
-    pub fn valid(&self, width: i64, height: i64) -> bool {
-        return self.row >= 0 && self.row < height && self.col >= 0 && self.col < width;
-    }
-
-    pub fn add(&self, other: &Self) -> Self {
-        return Coord::new(self.row + other.row, self.col + other.col);
-    }
-
-    pub fn m(&self, dir: Dir, width: i64, height: i64) -> Option<Self> {
-        let n = self.add(&dir.delta());
-        if n.valid(width, height) {
-            Some(n)
-        } else {
-            None
-        }
-    }
-}
-
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-pub enum Dir {
-    Up,
-    Left,
-    Down,
-    Right,
-}
-
-impl Dir {
-    pub fn delta(&self) -> Coord {
-        match self {
-            Dir::Up => Coord::new(-1, 0),
-            Dir::Left => Coord::new(0, -1),
-            Dir::Down => Coord::new(1, 0),
-            Dir::Right => Coord::new(0, 1),
-        }
-    }
-
-    pub fn opposite(&self) -> Dir {
-        match self {
-            Dir::Up => Dir::Down,
-            Dir::Left => Dir::Right,
-            Dir::Down => Dir::Up,
-            Dir::Right => Dir::Left,
-        }
-    }
-}
-
-const DIRS: [Dir; 4] = [Dir::Up, Dir::Left, Dir::Down, Dir::Right];
+use crate::{Board, Frontier};
 
 pub fn valid_dirs(hist: &Hist) -> Vec<Dir> {
     // dbg!(hist);
@@ -71,18 +12,18 @@ pub fn valid_dirs(hist: &Hist) -> Vec<Dir> {
             if steps < 4 {
                 vec![dir]
             } else if steps < 10 {
-                DIRS.clone()
+                Dir::ALL
                     .into_iter()
                     .filter(|d| *d != dir.opposite())
                     .collect()
             } else {
-                DIRS.clone()
+                Dir::ALL
                     .into_iter()
                     .filter(|d| *d != dir.opposite() && *d != dir)
                     .collect()
             }
         }
-        None => DIRS.to_vec(),
+        None => Dir::ALL.to_vec(),
     };
 
     // dbg!(&ret);
@@ -107,101 +48,47 @@ impl Hist {
     }
 }
 
-#[derive(PartialEq, Eq)]
-struct State(Coord, Hist, u32);
+// An ultra crucible, which has to move at least four blocks in a direction before it can turn or
+// stop, and at most ten.
+struct Crucible<'a>(&'a Board);
 
-impl PartialOrd for State {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
-    }
-}
+impl WeightedGraph for Crucible<'_> {
+    type Node = (Coord, Hist);
+    type Cost = u32;
+
+    fn edges(
+        &self,
+        (node, hist): &(Coord, Hist),
+    ) -> impl IntoIterator<Item = ((Coord, Hist), u32)> {
+        let (node, hist) = (*node, *hist);
 
-impl Ord for State {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        other.2.cmp(&self.2)
+        valid_dirs(&hist).into_iter().filter_map(move |d| {
+            let next = self.0.step(node, d)?;
+            Some(((next, hist.add(d)), self.0.cost(&next)))
+        })
     }
 }
 
-pub fn solve(b: &Board, start: Coord, target: Coord, width: i64, height: i64) -> u32 {
-    let mut heap: BinaryHeap<State> = BinaryHeap::new();
-
-    let initial_hist: Hist = Hist(0, None);
-
-    let mut dists: HashMap<(Coord, Hist), u32> = HashMap::new();
-    dists.insert((start, initial_hist), 0);
-
-    let mut parent: HashMap<Coord, Coord> = HashMap::new();
-
-    heap.push(State(start, initial_hist, 0));
-
-    while !heap.is_empty() {
-        let State(node, hist, cost) = heap.pop().unwrap();
-        // dbg!(node);
-        // dbg!(hist);
-        // dbg!(cost);
-
-        if node == target && hist.0 >= 4 {
-            return cost;
+pub fn solve(b: &Board, start: Coord, target: Coord) -> u32 {
+    let mut frontier = Frontier::new(b, "d17-p2");
+    let is_goal = |(c, hist): &(Coord, Hist)| {
+        if let Some(f) = frontier.as_mut() {
+            f.settle(*c);
         }
+        *c == target && hist.0 >= 4
+    };
+    let Some(path) = graph::dijkstra(&Crucible(b), (start, Hist(0, None)), is_goal) else {
+        return 0;
+    };
 
-        let stored_cost = dists.get(&(node, hist)).unwrap_or(&u32::MAX);
-        if cost > *stored_cost {
-            continue;
-        }
-
-        for d in valid_dirs(&hist) {
-            let next_coord = node.m(d, width, height);
-            if let Some(next_coord) = next_coord {
-                // dbg!(next_coord);
-                let next_cost = cost + b.get(&next_coord).unwrap();
-                let next_hist = hist.add(d);
-
-                let stored_cost = dists.get(&(next_coord, next_hist)).unwrap_or(&u32::MAX);
-
-                if next_cost < *stored_cost {
-                    parent.insert(node, next_coord);
-                    heap.push(State(next_coord, next_hist, next_cost));
-                    dists.insert((next_coord, next_hist), next_cost);
-                }
-            }
-        }
+    debug!("{} blocks to {target}", path.nodes.len() - 1);
+    if let Some(f) = frontier {
+        f.finish(&path.nodes.iter().map(|n| n.0).collect::<Vec<_>>());
     }
 
-    0
+    path.cost
 }
 
-type Board = HashMap<Coord, u32>;
-
-pub fn p2() -> std::io::Result<()> {
-    let file = File::open("d17/src/input.txt")?;
-    let buf_reader = BufReader::new(file);
-
-    let mut b: Board = HashMap::new();
-    let mut height = 0;
-    let mut width = 0;
-
-    for (row, l) in buf_reader.lines().enumerate() {
-        let row = row as i64;
-        width = 0;
-        for (col, c) in l?.chars().enumerate() {
-            let col = col as i64;
-            b.insert(Coord::new(row, col), c.to_digit(10).unwrap());
-            width += 1;
-        }
-        height += 1;
-    }
-
-    println!("{width}, {height}");
-
-    let result = solve(
-        &b,
-        Coord::new(0, 0),
-        Coord::new(height - 1, width - 1),
-        width,
-        height,
-    );
-
-    println!("{result}");
-
-    Ok(())
+pub fn p2(board: &Board) -> u32 {
+    solve(board, Coord::new(0, 0), board.target())
 }
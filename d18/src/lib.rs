@@ -0,0 +1,133 @@
+use common::{
+    parse::{labelled, tag, take_while1, unsigned, word, Parsed, Parser},
+    ParseError, ParseResult, Solution,
+};
+use grid::Dir;
+
+mod p1;
+mod p2;
+
+pub const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+fn parse_dir(s: &str) -> ParseResult<Dir> {
+    match s {
+        "U" => Ok(Dir::Up),
+        "R" => Ok(Dir::Right),
+        "L" => Ok(Dir::Left),
+        "D" => Ok(Dir::Down),
+        _ => Err(ParseError::at(s, "'U', 'R', 'D' or 'L'")),
+    }
+}
+
+fn hex_dir(s: &str) -> ParseResult<Dir> {
+    match s {
+        "0" => Ok(Dir::Right),
+        "1" => Ok(Dir::Down),
+        "2" => Ok(Dir::Left),
+        "3" => Ok(Dir::Up),
+        _ => Err(ParseError::at(s, "a direction digit 0-3")),
+    }
+}
+
+// The colour code, which is really another instruction: five hex digits of steps, then the
+// direction. Some samples only meant for part 1 leave it empty, which is an error for part 2 to
+// report rather than one in the input.
+fn parse_hex(s: &str) -> Parsed<'_, ParseResult<(Dir, i64)>> {
+    let (_, rest) = tag("(").parse(s)?;
+    if let Ok((_, rest)) = tag(")").parse(rest) {
+        return Ok((Err(ParseError::at(&s[..2], "a colour code")), rest));
+    }
+
+    let (_, rest) = tag("#").parse(rest)?;
+    let (digits, rest) = take_while1(|c| c.is_ascii_hexdigit(), "six hex digits").parse(rest)?;
+    if digits.len() != 6 {
+        return Err(ParseError::at(digits, "six hex digits"));
+    }
+    let (_, rest) = tag(")").parse(rest)?;
+
+    let (steps, dir) = digits.split_at(5);
+    // Five hex digits always fit.
+    let steps = i64::from_str_radix(steps, 16).unwrap();
+
+    Ok((Ok((hex_dir(dir)?, steps)), rest))
+}
+
+pub struct Instruction {
+    dir: Dir,
+    steps: u32,
+    hex: ParseResult<(Dir, i64)>,
+}
+
+impl Instruction {
+    // "R 6 (#70c710)"
+    pub fn new(l: &str) -> ParseResult<Self> {
+        let (dir, rest) = word(l)?;
+        let (steps, rest) = labelled(" ", unsigned).parse(rest)?;
+        let hex = labelled(" ", parse_hex).all(rest)?;
+
+        let dir = parse_dir(dir)?;
+
+        Ok(Self { dir, steps, hex })
+    }
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Model = Vec<Instruction>;
+    type Part1 = usize;
+    type Part2 = ParseResult<i64>;
+
+    fn parse(&self, input: &str) -> ParseResult<Self::Model> {
+        let mut plan: Vec<Instruction> = input
+            .lines()
+            .map(Instruction::new)
+            .collect::<ParseResult<_>>()?;
+
+        // Part 2 only gets the model, so locate its errors while we have the input.
+        for instruction in &mut plan {
+            if let Err(e) = &mut instruction.hex {
+                *e = e.clone().locate(input);
+            }
+        }
+
+        Ok(plan)
+    }
+
+    fn part1(&self, plan: &Self::Model) -> usize {
+        p1::p1(plan)
+    }
+
+    fn part2(&self, plan: &Self::Model) -> ParseResult<i64> {
+        p2::p2(plan)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Solution;
+
+    use super::*;
+
+    #[test]
+    fn colour_code() {
+        let plan = Day18.parse("R 6 (#70c710)\n").unwrap();
+        assert_eq!(plan[0].hex, Ok((Dir::Right, 461937)));
+    }
+
+    #[test]
+    fn empty_colour_code() {
+        let input = "R 2 (#000020)\nD 2 ()\nL 2 (#000022)\nU 2 (#000023)\n";
+        let plan = Day18.parse(input).unwrap();
+
+        let e = plan[1].hex.clone().unwrap_err();
+        let position = e.position.unwrap();
+        assert_eq!((position.line, position.column), (2, 5));
+        assert_eq!(e.expected, "a colour code");
+        assert_eq!(e.found, "\"()\"");
+
+        // Part 1 doesn't need it, and part 2 says what's missing instead of panicking.
+        assert_eq!(Day18.part1(&plan), 9);
+        assert_eq!(Day18.part2(&plan), Err(e));
+    }
+}
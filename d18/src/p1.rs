@@ -1,120 +1,31 @@
-use std::{
-    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
-    fs::File,
-    io::{BufRead, BufReader},
-    iter::zip,
-};
-
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-pub struct Coord {
-    row: i64,
-    col: i64,
-}
-
-impl Coord {
-    pub fn new(row: i64, col: i64) -> Self {
-        Self { row, col }
-    }
-
-    pub fn add(&self, other: &Self) -> Self {
-        return Coord::new(self.row + other.row, self.col + other.col);
-    }
-
-    pub fn m(&self, dir: Dir) -> Option<Self> {
-        let n = self.add(&dir.delta());
-        Some(n)
-    }
-}
-
-const DIRS: [Dir; 4] = [Dir::Up, Dir::Left, Dir::Down, Dir::Right];
+use std::collections::HashMap;
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-pub enum Dir {
-    Up,
-    Left,
-    Down,
-    Right,
-}
-
-impl Dir {
-    pub fn delta(&self) -> Coord {
-        match self {
-            Dir::Up => Coord::new(-1, 0),
-            Dir::Left => Coord::new(0, -1),
-            Dir::Down => Coord::new(1, 0),
-            Dir::Right => Coord::new(0, 1),
-        }
-    }
-
-    pub fn new(c: char) -> Self {
-        match c {
-            'U' => Self::Up,
-            'R' => Self::Right,
-            'L' => Self::Left,
-            'D' => Self::Down,
-            _ => unreachable!(),
-        }
-    }
+use grid::{
+    render::{self, Picture},
+    Coord, Dir,
+};
 
-    pub fn idx(&self) -> i64 {
-        match self {
-            Dir::Up => 0,
-            Dir::Right => 1,
-            Dir::Down => 2,
-            Dir::Left => 3,
-        }
-    }
+use crate::Instruction;
+use tracing::{debug, enabled, Level};
 
-    pub fn turning_number(&self, other: &Self) -> Option<i64> {
-        match (self, other) {
-            (Dir::Up, Dir::Up) => Some(0),
-            (Dir::Up, Dir::Left) => Some(-1),
-            (Dir::Up, Dir::Down) => None,
-            (Dir::Up, Dir::Right) => Some(1),
-            (Dir::Left, Dir::Up) => Some(1),
-            (Dir::Left, Dir::Left) => Some(0),
-            (Dir::Left, Dir::Down) => Some(-1),
-            (Dir::Left, Dir::Right) => None,
-            (Dir::Down, Dir::Up) => None,
-            (Dir::Down, Dir::Left) => Some(1),
-            (Dir::Down, Dir::Down) => Some(0),
-            (Dir::Down, Dir::Right) => Some(-1),
-            (Dir::Right, Dir::Up) => Some(-1),
-            (Dir::Right, Dir::Left) => None,
-            (Dir::Right, Dir::Down) => Some(1),
-            (Dir::Right, Dir::Right) => Some(0),
-        }
-    }
+type Board = HashMap<Coord, u32>;
 
-    pub fn turn(&self, number: i64) -> Dir {
-        if number > 0 {
-            match self {
-                Dir::Up => Dir::Right,
-                Dir::Left => Dir::Up,
-                Dir::Down => Dir::Left,
-                Dir::Right => Dir::Down,
-            }
-        } else if number < 0 {
-            match self {
-                Dir::Up => Dir::Left,
-                Dir::Left => Dir::Down,
-                Dir::Down => Dir::Right,
-                Dir::Right => Dir::Up,
-            }
-        } else {
-            unreachable!()
-        }
+// +1 for a right turn, -1 for a left turn. Over a whole loop, the sign tells us which side is
+// inside.
+fn turning_number(from: Dir, to: Dir) -> Option<i64> {
+    if to == from {
+        Some(0)
+    } else if to == from.turn_right() {
+        Some(1)
+    } else if to == from.turn_left() {
+        Some(-1)
+    } else {
+        None
     }
 }
 
-type Board = HashMap<Coord, u32>;
-
-pub fn p1() -> std::io::Result<()> {
-    let file = File::open("d18/src/input.txt")?;
-    let buf_reader = BufReader::new(file);
-
+pub fn p1(plan: &[Instruction]) -> usize {
     let mut b: Board = HashMap::new();
-    let mut size = 0;
 
     let mut position = Coord::new(0, 0);
     let mut dir_last: Option<Dir> = None;
@@ -125,24 +36,14 @@ pub fn p1() -> std::io::Result<()> {
     let mut min = Coord::new(0, 0);
     let mut max = Coord::new(0, 0);
 
-    for (row, l) in buf_reader.lines().enumerate() {
-        let l = l?;
-        let mut comps = l.split_whitespace();
-        let dir = comps.next().unwrap();
-        let steps = comps.next().unwrap();
-        let _ = comps.next().unwrap();
-
-        let dir = Dir::new(dir.chars().next().unwrap());
-        let steps = u32::from_str_radix(steps, 10).unwrap();
-
+    for &Instruction { dir, steps, .. } in plan {
         for _ in 0..steps {
-            position = position.m(dir).unwrap();
+            position = position.step(dir);
             path.push((position, Some(dir)));
             b.insert(position, 0);
 
-            match dir_last {
-                Some(dir_last) => turning += dir_last.turning_number(&dir).unwrap(),
-                None => {}
+            if let Some(dir_last) = dir_last {
+                turning += turning_number(dir_last, dir).unwrap()
             }
 
             dir_last = Some(dir);
@@ -159,43 +60,45 @@ pub fn p1() -> std::io::Result<()> {
 
     for (c, dir) in path {
         if let Some(d) = dir {
-            let turn_dir = d.turn(turning);
+            let turn_dir = if turning > 0 {
+                d.turn_right()
+            } else {
+                d.turn_left()
+            };
             let mut inner = c;
             loop {
-                inner = inner.m(turn_dir).unwrap();
+                inner = inner.step(turn_dir);
                 if b.contains_key(&inner) {
                     break;
                 }
                 filled.insert(inner, 0);
             }
         }
-
-        // for row in min.row..=max.row {
-        //     for col in min.col..=max.col {
-        //         if b.contains_key(&Coord::new(row, col)) {
-        //             print!("#")
-        //         } else {
-        //             print!(".")
-        //         }
-        //     }
-        //     println!("");
-        // }
-
-        // println!("-------");
     }
 
-    for row in min.row..=max.row {
-        for col in min.col..=max.col {
-            if b.contains_key(&Coord::new(row, col)) {
-                print!("#")
-            } else {
-                print!(".")
+    if enabled!(Level::DEBUG) {
+        let mut lagoon = String::new();
+        for row in min.row..=max.row {
+            for col in min.col..=max.col {
+                lagoon.push(if b.contains_key(&Coord::new(row, col)) {
+                    '#'
+                } else {
+                    '.'
+                });
             }
+            lagoon.push('\n');
         }
-        println!("");
+        debug!("trench:\n{lagoon}");
     }
 
-    println!("{}", filled.len());
+    if render::enabled() {
+        // The trench in white, and the dug out middle in blue.
+        let size = max - min + Coord::new(1, 1);
+        let mut picture = Picture::new(size.col as usize, size.row as usize, [0, 0, 0]);
+        picture.paint(filled.keys().map(|c| *c - min), [40, 90, 200]);
+        picture.paint(b.keys().map(|c| *c - min), [255, 255, 255]);
+        picture.save("d18-lagoon");
+    }
 
-    Ok(())
+    filled.len()
 }
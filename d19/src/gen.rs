@@ -0,0 +1,141 @@
+use std::collections::{BTreeSet, HashSet};
+
+use common::{Generate, Rng, Solution};
+
+use crate::{Day19, Field, Op, Part, System, Target};
+
+const FIELDS: [&str; 4] = ["x", "m", "a", "s"];
+
+// Where a step sends parts: accepted, rejected, or on to one of the `later` workflows.
+fn target(rng: &mut Rng, later: &[String]) -> String {
+    match rng.index(3) {
+        0 => "A".to_string(),
+        1 if !later.is_empty() => rng.choose(later).clone(),
+        _ => "R".to_string(),
+    }
+}
+
+// Follow `part` through the workflows from "in", the same way the puzzle describes it.
+fn accepted(system: &System, part: &Part) -> bool {
+    let mut wf = &system.workflows["in"];
+
+    loop {
+        let target = wf
+            .steps
+            .iter()
+            .find(|c| part.test(c))
+            .map_or(&wf.fallback, |c| &c.target);
+
+        match target {
+            Target::Accept => return true,
+            Target::Reject => return false,
+            Target::Workflow(name) => wf = &system.workflows[name],
+        }
+    }
+}
+
+impl Generate for Day19 {
+    // Workflows only send parts on to workflows after them, so there are no loops.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut names = vec!["in".to_string()];
+        let mut used = HashSet::from(["in".to_string()]);
+        while names.len() < size.max(1) {
+            let name: String = (0..3)
+                .map(|_| (b'a' + rng.below(26) as u8) as char)
+                .collect();
+            if used.insert(name.clone()) {
+                names.push(name);
+            }
+        }
+
+        let mut input = String::new();
+        for (i, name) in names.iter().enumerate() {
+            let mut steps: Vec<String> = (0..rng.range(0..=3))
+                .map(|_| {
+                    format!(
+                        "{}{}{}:{}",
+                        rng.choose(&FIELDS),
+                        rng.choose(&["<", ">"]),
+                        rng.range(1..=4000),
+                        target(rng, &names[i + 1..])
+                    )
+                })
+                .collect();
+            steps.push(target(rng, &names[i + 1..]));
+
+            input += &format!("{name}{{{}}}\n", steps.join(","));
+        }
+
+        input += "\n";
+        for _ in 0..size.max(1) * 2 {
+            let ratings: Vec<String> = FIELDS
+                .iter()
+                .map(|f| format!("{f}={}", rng.range(1..=4000)))
+                .collect();
+            input += &format!("{{{}}}\n", ratings.join(","));
+        }
+
+        input
+    }
+
+    fn reference1(input: &str) -> Option<i64> {
+        let system = Day19.parse(input).ok()?;
+
+        Some(
+            system
+                .parts
+                .iter()
+                .filter(|p| accepted(&system, p))
+                .map(|p| p.rating())
+                .sum(),
+        )
+    }
+
+    // Every condition splits the ratings at some value, and between splits all parts go the same
+    // way. So try one part from each box between the splits, and count the whole box.
+    fn reference2(input: &str) -> Option<i64> {
+        let system = Day19.parse(input).ok()?;
+
+        let mut cuts: [BTreeSet<i64>; 4] = std::array::from_fn(|_| BTreeSet::from([1, 4001]));
+        for c in system.workflows.values().flat_map(|w| &w.steps) {
+            let field = match c.field {
+                Field::X => 0,
+                Field::M => 1,
+                Field::A => 2,
+                Field::S => 3,
+            };
+            cuts[field].insert(match c.op {
+                Op::Lt => c.value,
+                Op::Gt => c.value + 1,
+            });
+        }
+
+        // The start and length of every box along each field.
+        let boxes: Vec<Vec<(i64, i64)>> = cuts
+            .iter()
+            .map(|c| {
+                let c: Vec<i64> = c
+                    .iter()
+                    .copied()
+                    .filter(|v| (1..=4001).contains(v))
+                    .collect();
+                c.windows(2).map(|w| (w[0], w[1] - w[0])).collect()
+            })
+            .collect();
+
+        let mut total = 0;
+        for &(x, xl) in &boxes[0] {
+            for &(m, ml) in &boxes[1] {
+                for &(a, al) in &boxes[2] {
+                    for &(s, sl) in &boxes[3] {
+                        if accepted(&system, &Part { x, m, a, s }) {
+                            total += xl * ml * al * sl;
+                        }
+                    }
+                }
+            }
+        }
+
+        Some(total)
+    }
+}
@@ -0,0 +1,220 @@
+use std::{collections::HashMap, fmt::Display};
+
+use common::{
+    parse::{block, labelled, lines, list, opt, tag, unsigned, word, Parsed, Parser},
+    ParseError, ParseResult, Solution,
+};
+
+mod gen;
+mod p1;
+mod p2;
+
+pub const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+#[derive(Debug)]
+pub enum Field {
+    X,
+    M,
+    A,
+    S,
+}
+
+impl Field {
+    pub fn new(s: &str) -> ParseResult<Self> {
+        match s {
+            "x" => Ok(Self::X),
+            "m" => Ok(Self::M),
+            "a" => Ok(Self::A),
+            "s" => Ok(Self::S),
+            _ => Err(ParseError::at(s, "'x', 'm', 'a' or 's'")),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Op {
+    Lt,
+    Gt,
+}
+
+impl Op {
+    pub fn new(s: &str) -> ParseResult<Self> {
+        match s {
+            "<" => Ok(Self::Lt),
+            ">" => Ok(Self::Gt),
+            _ => Err(ParseError::at(s, "'<' or '>'")),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Target {
+    Workflow(String),
+    Accept,
+    Reject,
+}
+
+impl Target {
+    pub fn new(s: &str) -> Self {
+        match s {
+            "A" => Self::Accept,
+            "R" => Self::Reject,
+            _ => Self::Workflow(s.to_string()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Condition {
+    field: Field,
+    op: Op,
+    value: i64,
+    target: Target,
+    original: String,
+}
+
+impl Condition {
+    // "a<2006:qkq", with the name of the target as a slice of `s`.
+    pub fn parse(s: &str) -> Parsed<'_, (Self, &str)> {
+        let (field, rest) = word(s)?;
+        if !rest.starts_with(['<', '>']) {
+            return Err(ParseError::at(s, "a condition like \"x<10:A\""));
+        }
+        let (op, rest) = rest.split_at(1);
+        let (value, rest) = unsigned(rest)?;
+        let (target, rest) = labelled(":", word).parse(rest)?;
+
+        let condition = Self {
+            field: Field::new(field)?,
+            op: Op::new(op)?,
+            value,
+            target: Target::new(target),
+            original: s[..s.len() - rest.len()].to_string(),
+        };
+
+        Ok(((condition, target), rest))
+    }
+}
+
+impl Display for Condition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.original)
+    }
+}
+
+#[derive(Debug)]
+pub struct Workflow {
+    name: String,
+    steps: Vec<Condition>,
+    fallback: Target,
+}
+
+impl Workflow {
+    // "px{a<2006:qkq,m>2090:A,rfg}", with the names of the workflows it sends parts to, as slices
+    // of `s` to point at any that don't exist.
+    pub fn parse(s: &str) -> Parsed<'_, (Self, Vec<&str>)> {
+        let (name, rest) = word(s)?;
+        let (_, rest) = tag("{").parse(rest)?;
+        // Every step but the last is a condition.
+        let conditions = |s| {
+            let (steps, rest) = list(Condition::parse, tag(",")).parse(s)?;
+            tag(",").parse(rest).map(|(_, rest)| (steps, rest))
+        };
+        let (steps, rest) = opt(conditions).parse(rest)?;
+        let (fallback, rest) = word(rest)?;
+        let (_, rest) = tag("}").parse(rest)?;
+
+        let (steps, mut targets): (Vec<_>, Vec<_>) = steps.unwrap_or_default().into_iter().unzip();
+        targets.push(fallback);
+        targets.retain(|t| matches!(Target::new(t), Target::Workflow(_)));
+
+        let workflow = Self {
+            name: name.to_string(),
+            steps,
+            fallback: Target::new(fallback),
+        };
+
+        Ok(((workflow, targets), rest))
+    }
+}
+
+#[derive(Debug)]
+pub struct Part {
+    x: i64,
+    m: i64,
+    a: i64,
+    s: i64,
+}
+
+impl Part {
+    // "{x=787,m=2655,a=1222,s=2876}"
+    pub fn parse(s: &str) -> Parsed<'_, Self> {
+        let (x, rest) = labelled("{x=", unsigned).parse(s)?;
+        let (m, rest) = labelled(",m=", unsigned).parse(rest)?;
+        let (a, rest) = labelled(",a=", unsigned).parse(rest)?;
+        let (s, rest) = labelled(",s=", unsigned).parse(rest)?;
+        let (_, rest) = tag("}").parse(rest)?;
+
+        Ok((Self { x, m, a, s }, rest))
+    }
+
+    pub fn test(&self, cond: &Condition) -> bool {
+        let value = match cond.field {
+            Field::X => self.x,
+            Field::M => self.m,
+            Field::A => self.a,
+            Field::S => self.s,
+        };
+
+        match cond.op {
+            Op::Lt => value < cond.value,
+            Op::Gt => value > cond.value,
+        }
+    }
+
+    pub fn rating(&self) -> i64 {
+        self.x + self.m + self.a + self.s
+    }
+}
+
+pub struct System {
+    workflows: HashMap<String, Workflow>,
+    parts: Vec<Part>,
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Model = System;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, input: &str) -> ParseResult<Self::Model> {
+        let (workflows, rest) = block(lines(Workflow::parse)).parse(input)?;
+        let (workflows, targets): (HashMap<_, _>, Vec<_>) = workflows
+            .into_iter()
+            .map(|(wf, targets)| ((wf.name.clone(), wf), targets))
+            .unzip();
+
+        for name in targets.into_iter().flatten() {
+            if !workflows.contains_key(name) {
+                return Err(ParseError::at(name, "the name of a workflow"));
+            }
+        }
+        if !workflows.contains_key("in") {
+            return Err(ParseError::at(input, "a workflow named \"in\""));
+        }
+
+        let parts = lines(Part::parse).all(rest)?;
+
+        Ok(System { workflows, parts })
+    }
+
+    fn part1(&self, system: &Self::Model) -> i64 {
+        p1::p1(system)
+    }
+
+    fn part2(&self, system: &Self::Model) -> i64 {
+        p2::p2(system)
+    }
+}
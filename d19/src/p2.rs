@@ -1,228 +1,37 @@
-use std::{
-    collections::HashMap,
-    fmt::Display,
-    fs::File,
-    io::{BufRead, BufReader},
-};
-
-#[derive(Debug)]
-pub enum Field {
-    X,
-    M,
-    A,
-    S,
-}
-
-impl Field {
-    pub fn new(c: char) -> Self {
-        match c {
-            'x' => Self::X,
-            'm' => Self::M,
-            'a' => Self::A,
-            's' => Self::S,
-            _ => unreachable!(),
-        }
-    }
-}
-
-#[derive(Debug)]
-pub enum Op {
-    Lt,
-    Gt,
-}
-
-impl Op {
-    pub fn new(c: char) -> Self {
-        match c {
-            '<' => Self::Lt,
-            '>' => Self::Gt,
-            _ => unreachable!(),
-        }
-    }
-}
-
-#[derive(Debug)]
-pub enum Target {
-    Workflow(String),
-    Accept,
-    Reject,
-}
-
-impl Target {
-    pub fn new(s: &str) -> Self {
-        match s {
-            "A" => Self::Accept,
-            "R" => Self::Reject,
-            _ => Self::Workflow(s.to_string()),
-        }
-    }
-}
-
-#[derive(Debug)]
-pub struct Condition {
-    field: Field,
-    op: Op,
-    value: i64,
-    target: Target,
-    original: String,
-}
-
-impl Condition {
-    pub fn new(s: &str) -> Self {
-        let (cond, target) = s.split_once(':').unwrap();
-        let target = Target::new(target);
-
-        let (field, op, value) = if cond.contains('<') {
-            let (field, value) = cond.split_once('<').unwrap();
-            (
-                Field::new(field.chars().next().unwrap()),
-                Op::Lt,
-                i64::from_str_radix(value, 10).unwrap(),
-            )
-        } else {
-            let (field, value) = cond.split_once('>').unwrap();
-            (
-                Field::new(field.chars().next().unwrap()),
-                Op::Gt,
-                i64::from_str_radix(value, 10).unwrap(),
-            )
-        };
-
-        Self {
-            field,
-            op,
-            value,
-            target,
-            original: s.to_string(),
-        }
-    }
-}
-
-impl Display for Condition {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.original)
-    }
-}
-
-#[derive(Debug)]
-pub struct Workflow {
-    name: String,
-    steps: Vec<Condition>,
-    fallback: Target,
-}
-
-impl Workflow {
-    pub fn new(s: &str) -> Self {
-        let (name, rest) = s.split_once('{').unwrap();
-        let rest = rest.replace('}', "");
-
-        let workflows: Vec<&str> = rest.split(',').collect();
-
-        let conditions = &workflows[0..workflows.len() - 1];
-        let conditions = conditions.iter().map(|c| Condition::new(c)).collect();
-        let fallback = Target::new(workflows.last().unwrap());
-
-        Self {
-            name: name.to_string(),
-            steps: conditions,
-            fallback,
-        }
-    }
-}
-
-#[derive(Debug)]
-pub struct Part {
-    x: i64,
-    m: i64,
-    a: i64,
-    s: i64,
-}
-
-impl Part {
-    pub fn new(s: &str) -> Self {
-        let s = s.replace('{', "").replace('}', "");
-
-        let mut fields = s.split(",");
-
-        let x = i64::from_str_radix(fields.next().unwrap().split_once('=').unwrap().1, 10).unwrap();
-        let m = i64::from_str_radix(fields.next().unwrap().split_once('=').unwrap().1, 10).unwrap();
-        let a = i64::from_str_radix(fields.next().unwrap().split_once('=').unwrap().1, 10).unwrap();
-        let s = i64::from_str_radix(fields.next().unwrap().split_once('=').unwrap().1, 10).unwrap();
-
-        Self { x, m, a, s }
-    }
-
-    pub fn test(&self, cond: &Condition) -> bool {
-        let value = match cond.field {
-            Field::X => self.x,
-            Field::M => self.m,
-            Field::A => self.a,
-            Field::S => self.s,
-        };
-
-        match cond.op {
-            Op::Lt => value < cond.value,
-            Op::Gt => value > cond.value,
+use std::{collections::HashMap, fmt::Display, ops::Range};
+
+use crate::{Condition, Field, Op, System, Target, Workflow};
+use interval::Interval;
+use tracing::{enabled, trace, Level};
+
+// Every rating runs from 1 to 4000.
+const RATINGS: Range<i64> = 1..4001;
+
+// Returns the ratings that pass the condition and the ones that fail it.
+fn split(
+    range: &Interval<i64>,
+    cond: &Condition,
+) -> (Option<Interval<i64>>, Option<Interval<i64>>) {
+    match cond.op {
+        Op::Lt => range.split_at(cond.value),
+        Op::Gt => {
+            let (fail, pass) = range.split_at(cond.value + 1);
+            (pass, fail)
         }
     }
-
-    pub fn rating(&self) -> i64 {
-        self.x + self.m + self.a + self.s
-    }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-struct Range {
-    start: i64,
-    end: i64,
-}
-
-impl Range {
-    pub fn new(start: i64, end: i64) -> Self {
-        Self { start, end }
-    }
-
-    pub fn size(&self) -> i64 {
-        self.end - self.start
-    }
-
-    pub fn intersect(&self, other: &Self) -> Option<Self> {
-        let start = self.start.max(other.start);
-        let end = self.end.min(other.end);
-
-        if end < start {
-            None
-        } else {
-            Some(Range { start, end })
-        }
-    }
-
-    // Returns two ranges, one fulfilling and one failing
-    pub fn split(&self, cond: &Condition) -> (Option<Self>, Option<Self>) {
-        let (pass, fail) = match cond.op {
-            Op::Lt => (Range::new(0, cond.value), Range::new(cond.value, 4001)),
-            Op::Gt => (
-                Range::new(cond.value + 1, 4001),
-                Range::new(0, cond.value + 1),
-            ),
-        };
-
-        (pass.intersect(self), fail.intersect(self))
-    }
-}
-
-impl Display for Range {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:04}-{:04}", self.start, self.end - 1)
-    }
+// Inclusive, to match the puzzle.
+fn show(range: &Interval<i64>) -> String {
+    format!("{:04}-{:04}", range.start(), range.end() - 1)
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 struct PPart {
-    x: Range,
-    m: Range,
-    a: Range,
-    s: Range,
+    x: Interval<i64>,
+    m: Interval<i64>,
+    a: Interval<i64>,
+    s: Interval<i64>,
 }
 
 impl Display for PPart {
@@ -230,7 +39,10 @@ impl Display for PPart {
         write!(
             f,
             "x: {}, m: {}, a: {}, s: {}",
-            self.x, self.m, self.a, self.s
+            show(&self.x),
+            show(&self.m),
+            show(&self.a),
+            show(&self.s)
         )
     }
 }
@@ -238,14 +50,14 @@ impl Display for PPart {
 impl PPart {
     pub fn new() -> Self {
         Self {
-            x: Range::new(1, 4001),
-            m: Range::new(1, 4001),
-            a: Range::new(1, 4001),
-            s: Range::new(1, 4001),
+            x: RATINGS.into(),
+            m: RATINGS.into(),
+            a: RATINGS.into(),
+            s: RATINGS.into(),
         }
     }
 
-    pub fn set(&self, f: &Field, r: &Range) -> Self {
+    pub fn set(&self, f: &Field, r: &Interval<i64>) -> Self {
         match f {
             Field::X => Self { x: *r, ..*self },
             Field::M => Self { m: *r, ..*self },
@@ -254,7 +66,7 @@ impl PPart {
         }
     }
 
-    pub fn get(&self, field: &Field) -> Range {
+    pub fn get(&self, field: &Field) -> Interval<i64> {
         match field {
             Field::X => self.x,
             Field::M => self.m,
@@ -264,13 +76,13 @@ impl PPart {
     }
 
     pub fn size(&self) -> i64 {
-        self.x.size() * self.m.size() * self.a.size() * self.s.size()
+        self.x.len() * self.m.len() * self.a.len() * self.s.len()
     }
 
     pub fn apply(&self, cond: &Condition) -> (Option<Self>, Option<Self>) {
         let range = self.get(&cond.field);
 
-        let (rpass, rfail) = range.split(&cond);
+        let (rpass, rfail) = split(&range, cond);
 
         let pass = rpass.map(|p| self.set(&cond.field, &p));
         let fail = rfail.map(|p| self.set(&cond.field, &p));
@@ -279,89 +91,66 @@ impl PPart {
     }
 
     pub fn valid(&self, workflow: &Workflow, wfs: &HashMap<String, Workflow>) -> Vec<PPart> {
-        println!("{}: {}", workflow.name, self);
-        println!("---------");
+        trace!("{}: {}", workflow.name, self);
         let mut pparts: Vec<PPart> = Vec::new();
 
-        let mut range = *self;
+        // Parts that haven't passed a condition yet. Once a condition passes all of them, there's
+        // nothing left for the later steps or the fallback.
+        let mut rest = Some(*self);
 
         for c in &workflow.steps {
-            println!("STEP: {}", c);
-            let (pass, fail) = range.apply(&c);
-            println!("PASS: {}", pass.unwrap());
-            println!("FAIL: {}", fail.unwrap());
-            match pass {
-                Some(pass) => match &c.target {
+            let Some(range) = rest else {
+                break;
+            };
+
+            trace!("STEP: {}", c);
+            let (pass, fail) = range.apply(c);
+            trace!("PASS: {:?}", pass);
+            trace!("FAIL: {:?}", fail);
+            if let Some(pass) = pass {
+                match &c.target {
                     Target::Workflow(s) => {
-                        println!("RECURSE: {}", pass);
-                        let next = wfs.get(s).unwrap();
-                        pparts.extend(pass.valid(&next, wfs));
+                        trace!("RECURSE: {}", pass);
+                        let next = &wfs[s];
+                        pparts.extend(pass.valid(next, wfs));
                     }
                     Target::Accept => {
-                        println!("ACCEPT: {}", pass);
+                        trace!("ACCEPT: {}", pass);
                         pparts.push(pass);
                     }
                     Target::Reject => {}
-                },
-                None => {}
-            }
-
-            match fail {
-                Some(fail) => {
-                    println!("FAIL: {}", fail);
-                    range = fail
                 }
-                None => {}
             }
+
+            rest = fail;
         }
 
-        match &workflow.fallback {
-            Target::Workflow(s) => {
-                let next = wfs.get(s).unwrap();
-                pparts.extend(range.valid(&next, wfs));
-            }
-            Target::Accept => {
-                pparts.push(range);
+        if let Some(range) = rest {
+            match &workflow.fallback {
+                Target::Workflow(s) => {
+                    let next = &wfs[s];
+                    pparts.extend(range.valid(next, wfs));
+                }
+                Target::Accept => {
+                    pparts.push(range);
+                }
+                Target::Reject => {}
             }
-            Target::Reject => {}
         }
 
-        println!("RESULT FOR {}:", workflow.name);
-        for p in &pparts {
-            println!("{}", p);
+        if enabled!(Level::TRACE) {
+            let result: Vec<String> = pparts.iter().map(|p| p.to_string()).collect();
+            trace!("RESULT FOR {}: {}", workflow.name, result.join(", "));
         }
-        println!("-------");
 
         pparts
     }
 }
 
-pub fn p2() -> std::io::Result<()> {
-    let file = File::open("d19/src/input.txt")?;
-    let buf_reader = BufReader::new(file);
-    let mut lines = buf_reader.lines();
-
-    let mut workflows: HashMap<String, Workflow> = HashMap::new();
-    let mut parts: Vec<Part> = Vec::new();
-
-    for l in lines
-        .by_ref()
-        .take_while(|l| !l.as_ref().unwrap().is_empty())
-    {
-        let l = l?;
-        let wf = Workflow::new(&l);
-        workflows.insert(wf.name.clone(), wf);
-    }
-
-    for l in lines.by_ref() {
-        let l = l?;
-        parts.push(Part::new(&l));
-    }
-
-    let result = PPart::new().valid(workflows.get("in").unwrap(), &workflows);
+pub fn p2(system: &System) -> i64 {
+    let workflows = &system.workflows;
 
-    let size: i64 = result.iter().map(|r| r.size()).sum();
-    println!("{size}");
+    let result = PPart::new().valid(&workflows["in"], workflows);
 
-    Ok(())
+    result.iter().map(|r| r.size()).sum()
 }
@@ -0,0 +1,154 @@
+use std::collections::{HashMap, VecDeque};
+
+use common::{Generate, ParseResult, Rng, Solution};
+
+use crate::Day20;
+
+// Give up on part 2 if rx hasn't had a low pulse after this many presses.
+const MAX_PRESSES: usize = 100_000;
+
+// A plain simulation of the modules, kept apart from the solution's.
+struct Machine<'a> {
+    kinds: HashMap<&'a str, char>,
+    outputs: HashMap<&'a str, Vec<&'a str>>,
+    on: HashMap<&'a str, bool>,
+    memory: HashMap<&'a str, HashMap<&'a str, bool>>,
+}
+
+impl<'a> Machine<'a> {
+    fn new(input: &'a str) -> Option<Self> {
+        let mut machine = Self {
+            kinds: HashMap::new(),
+            outputs: HashMap::new(),
+            on: HashMap::new(),
+            memory: HashMap::new(),
+        };
+
+        for l in input.lines() {
+            let (module, targets) = l.split_once(" -> ")?;
+            let (kind, name) = match module.strip_prefix(['%', '&']) {
+                Some(name) => (module.chars().next()?, name),
+                None => ('b', module),
+            };
+            machine.kinds.insert(name, kind);
+            machine.outputs.insert(name, targets.split(", ").collect());
+        }
+
+        for (from, targets) in &machine.outputs {
+            for t in targets {
+                machine.memory.entry(t).or_default().insert(from, false);
+            }
+        }
+
+        Some(machine)
+    }
+
+    // Push the button, returning how many low and high pulses were sent, and whether rx got a low
+    // one.
+    fn press(&mut self) -> (i64, i64, bool) {
+        let (mut low, mut high, mut rx) = (0, 0, false);
+
+        let mut queue = VecDeque::from([("button", "broadcaster", false)]);
+        while let Some((from, to, pulse)) = queue.pop_front() {
+            if pulse {
+                high += 1;
+            } else {
+                low += 1;
+                rx |= to == "rx";
+            }
+
+            let send = match self.kinds.get(to) {
+                Some('b') => pulse,
+                Some('%') if !pulse => {
+                    let on = self.on.entry(to).or_default();
+                    *on = !*on;
+                    *on
+                }
+                Some('&') => {
+                    let memory = self.memory.get_mut(to).unwrap();
+                    memory.insert(from, pulse);
+                    !memory.values().all(|p| *p)
+                }
+                _ => continue,
+            };
+
+            for t in &self.outputs[to] {
+                queue.push_back((to, t, send));
+            }
+        }
+
+        (low, high, rx)
+    }
+}
+
+impl Generate for Day20 {
+    // Like the real inputs: a few binary counters, each a chain of flip-flops that a conjunction
+    // resets when they reach some count. rx gets a low pulse once all the counters reset together.
+    fn generate(rng: &mut Rng, _size: usize) -> String {
+        let mut lines = Vec::new();
+        let mut starts = Vec::new();
+        let mut inverters = Vec::new();
+
+        for counter in 0..rng.range(1..=3) {
+            let id = (b'a' + counter as u8) as char;
+            let bits = rng.range(2..=4) as usize;
+            // Odd, so the hub resets the first flip-flop, and with the top bit set, so all the
+            // flip-flops get used.
+            let count = rng.range(1 << (bits - 1)..=(1 << bits) - 1) | 1 | 1 << (bits - 1);
+
+            let flop = |i: usize| format!("f{id}{}", (b'a' + i as u8) as char);
+            let (hub, inverter) = (format!("h{id}"), format!("i{id}"));
+            let mut resets = vec![flop(0)];
+
+            for i in 0..bits {
+                let mut targets = Vec::new();
+                if i + 1 < bits {
+                    targets.push(flop(i + 1));
+                }
+                if count >> i & 1 == 1 {
+                    targets.push(hub.clone());
+                } else {
+                    resets.push(flop(i));
+                }
+                rng.shuffle(&mut targets);
+                lines.push(format!("%{} -> {}", flop(i), targets.join(", ")));
+            }
+
+            resets.push(inverter.clone());
+            rng.shuffle(&mut resets);
+            lines.push(format!("&{hub} -> {}", resets.join(", ")));
+            lines.push(format!("&{inverter} -> fin"));
+
+            starts.push(flop(0));
+            inverters.push(inverter);
+        }
+
+        rng.shuffle(&mut starts);
+        lines.push(format!("broadcaster -> {}", starts.join(", ")));
+        lines.push("&fin -> rx".to_string());
+        rng.shuffle(&mut lines);
+
+        lines.join("\n") + "\n"
+    }
+
+    fn reference1(input: &str) -> Option<i64> {
+        Day20.parse(input).ok()?;
+        let mut machine = Machine::new(input)?;
+
+        let (mut low, mut high) = (0, 0);
+        for _ in 0..1000 {
+            let (l, h, _) = machine.press();
+            low += l;
+            high += h;
+        }
+
+        Some(low * high)
+    }
+
+    fn reference2(input: &str) -> Option<ParseResult<usize>> {
+        Day20.parse(input).ok()?;
+        let mut machine = Machine::new(input)?;
+
+        (1..=MAX_PRESSES).find(|_| machine.press().2).map(Ok)
+    }
+}
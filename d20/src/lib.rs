@@ -0,0 +1,315 @@
+use std::collections::{HashMap, VecDeque};
+
+use common::{
+    parse::{labelled, list, tag, take_while1, word, Parser},
+    ParseError, ParseResult, Solution,
+};
+use tracing::trace;
+
+mod gen;
+mod p1;
+mod p2;
+
+pub const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+#[derive(Debug, Clone)]
+pub struct FFState {
+    state: bool,
+}
+
+impl Default for FFState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FFState {
+    pub fn new() -> Self {
+        Self { state: false }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ConjState {
+    inputs: HashMap<String, bool>,
+}
+
+impl Default for ConjState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConjState {
+    pub fn new() -> Self {
+        Self {
+            inputs: HashMap::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum ModuleType {
+    Broadcast,
+    FlipFlop(FFState),
+    Conjunction(ConjState),
+    Output,
+}
+
+#[derive(Debug, Clone)]
+pub struct Module {
+    name: String,
+    ty: ModuleType,
+}
+
+impl Module {
+    pub fn new(s: &str) -> ParseResult<Self> {
+        if let Some(name) = s.strip_prefix('%') {
+            Ok(Self {
+                name: name.to_string(),
+                ty: ModuleType::FlipFlop(FFState::new()),
+            })
+        } else if let Some(name) = s.strip_prefix('&') {
+            Ok(Self {
+                name: name.to_string(),
+                ty: ModuleType::Conjunction(ConjState::new()),
+            })
+        } else if s == "broadcaster" {
+            Ok(Self {
+                name: s.to_string(),
+                ty: ModuleType::Broadcast,
+            })
+        } else {
+            Err(ParseError::at(
+                s,
+                "\"broadcaster\" or a module name prefixed with '%' or '&'",
+            ))
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Event {
+    from: String,
+    target: String,
+    pulse: bool,
+}
+
+#[derive(Clone)]
+pub struct Circuit {
+    modules: HashMap<String, Module>,
+    outputs: HashMap<String, Vec<String>>,
+    inputs: HashMap<String, Vec<String>>,
+
+    high: i64,
+    low: i64,
+}
+
+impl Default for Circuit {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Circuit {
+    pub fn new() -> Self {
+        Self {
+            modules: HashMap::new(),
+            outputs: HashMap::new(),
+            inputs: HashMap::new(),
+            high: 0,
+            low: 0,
+        }
+    }
+
+    // "%a -> b, c"
+    pub fn add_module(&mut self, l: &str) -> ParseResult<()> {
+        let (name, rest) = take_while1(|c| c != ' ', "a module").parse(l)?;
+        let targets = labelled(" ->", list(word, tag(", "))).all(rest)?;
+
+        let module = Module::new(name)?;
+        self.modules.insert(module.name.clone(), module.clone());
+
+        for t in targets {
+            let (from, to) = (&module.name, t);
+            if !self.outputs.contains_key(from) {
+                self.outputs.insert(from.to_string(), Vec::new());
+            }
+            if !self.inputs.contains_key(to) {
+                self.inputs.insert(to.to_string(), Vec::new());
+            }
+
+            self.outputs.get_mut(from).unwrap().push(to.to_string());
+            self.inputs.get_mut(to).unwrap().push(from.to_string());
+        }
+
+        Ok(())
+    }
+
+    pub fn post_init(&mut self) {
+        for (name, v) in self.modules.iter_mut() {
+            if let ModuleType::Conjunction(ref mut state) = &mut v.ty {
+                for i in self.inputs.get(name).into_iter().flatten() {
+                    state.inputs.insert(i.clone(), false);
+                }
+            }
+
+            if !self.outputs.contains_key(name) {
+                self.outputs.insert(name.clone(), Vec::new());
+            }
+
+            if !self.inputs.contains_key(name) {
+                self.inputs.insert(name.clone(), Vec::new());
+            }
+        }
+
+        self.modules.insert(
+            "output".to_string(),
+            Module {
+                name: "output".to_string(),
+                ty: ModuleType::Output,
+            },
+        );
+    }
+
+    pub fn propagate_one(&mut self, event: &Event) -> Vec<Event> {
+        trace!("{} -> {}: {}", event.from, event.target, event.pulse);
+        if event.pulse {
+            self.high += 1;
+        } else {
+            self.low += 1;
+        }
+        let mut events = Vec::new();
+
+        if !self.modules.contains_key(&event.target) {
+            return Vec::new();
+        }
+
+        let target = self.modules.get_mut(&event.target).unwrap();
+        match &mut target.ty {
+            ModuleType::Broadcast => {
+                for o in self.outputs.get(&target.name).unwrap() {
+                    events.push(Event {
+                        from: target.name.to_string(),
+                        target: o.to_string(),
+                        pulse: event.pulse,
+                    })
+                }
+            }
+            ModuleType::FlipFlop(ref mut state) => {
+                if !event.pulse {
+                    state.state = !state.state;
+                    for o in self.outputs.get(&target.name).unwrap() {
+                        events.push(Event {
+                            from: target.name.to_string(),
+                            target: o.to_string(),
+                            pulse: state.state,
+                        })
+                    }
+                }
+            }
+            ModuleType::Conjunction(ref mut state) => {
+                state.inputs.insert(event.from.clone(), event.pulse);
+
+                let output = !state.inputs.values().all(|b| *b);
+
+                for o in self.outputs.get(&target.name).unwrap() {
+                    events.push(Event {
+                        from: target.name.to_string(),
+                        target: o.to_string(),
+                        pulse: output,
+                    })
+                }
+            }
+            ModuleType::Output => {}
+        }
+
+        events
+    }
+
+    // Push the button once, returning every pulse that was sent as a result.
+    pub fn press(&mut self) -> Vec<Event> {
+        let mut sent: Vec<Event> = Vec::new();
+
+        let mut queue: VecDeque<Event> = VecDeque::new();
+        queue.push_back(Event {
+            from: "button".to_string(),
+            target: "broadcaster".to_string(),
+            pulse: false,
+        });
+
+        while let Some(event) = queue.pop_front() {
+            let result = self.propagate_one(&event);
+            for r in result {
+                queue.push_back(r);
+            }
+            sent.push(event);
+        }
+
+        sent
+    }
+}
+
+/// The circuit, with the module sending to rx that part 2 watches. The samples have no rx, which
+/// only part 2 needs.
+pub struct Network {
+    circuit: Circuit,
+    feeder: ParseResult<String>,
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Model = Network;
+    type Part1 = i64;
+    type Part2 = ParseResult<usize>;
+
+    fn parse(&self, input: &str) -> ParseResult<Self::Model> {
+        let mut circuit = Circuit::new();
+
+        for l in input.lines() {
+            circuit.add_module(l)?;
+        }
+
+        circuit.post_init();
+
+        // Part 2 only gets the model, so locate its error while we have the input.
+        let feeder = match circuit.inputs.get("rx").and_then(|i| i.first()) {
+            Some(feeder) => Ok(feeder.clone()),
+            None => {
+                Err(ParseError::after(input.trim_end(), "a module sending to rx").locate(input))
+            }
+        };
+
+        Ok(Network { circuit, feeder })
+    }
+
+    fn part1(&self, network: &Self::Model) -> i64 {
+        p1::p1(&network.circuit)
+    }
+
+    fn part2(&self, network: &Self::Model) -> ParseResult<usize> {
+        p2::p2(
+            &network.circuit,
+            network.feeder.as_ref().map_err(Clone::clone)?,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Solution;
+
+    use super::*;
+
+    #[test]
+    fn no_rx() {
+        let input = "broadcaster -> a\n%a -> output\n";
+        let network = Day20.parse(input).unwrap();
+
+        assert_eq!(Day20.part1(&network), 2500 * 500);
+        let e = Day20.part2(&network).unwrap_err();
+        assert_eq!(e.expected, "a module sending to rx");
+        assert_eq!(e.position.map(|p| (p.line, p.column)), Some((2, 13)));
+    }
+}
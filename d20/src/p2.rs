@@ -1,249 +1,109 @@
-use std::{
-    collections::{HashMap, VecDeque},
-    fs::File,
-    io::{BufRead, BufReader},
-    process::Output,
-};
+use std::collections::{BTreeSet, VecDeque};
+
+use crate::{Circuit, ModuleType};
+use common::{ParseError, ParseResult};
+use cycle::Cycle;
+use tracing::debug;
+
+pub fn p2(circuit: &Circuit, feeder: &str) -> ParseResult<usize> {
+    // rx is fed by a single conjunction, which only sends a low pulse once all of its inputs have
+    // sent it a high pulse in the same press. Each input is driven by its own counter of modules,
+    // so we find the loop each counter goes round, and when in that loop its input fires.
+    let mut loops = Vec::new();
+    for input in &circuit.inputs[feeder] {
+        let counter = upstream(circuit, input);
+
+        let cycle = Cycle::find_by(
+            (circuit.clone(), false),
+            |(circuit, _)| {
+                let mut circuit = circuit.clone();
+                let fired = circuit
+                    .press()
+                    .iter()
+                    .any(|e| e.from == *input && e.target == feeder && e.pulse);
+                (circuit, fired)
+            },
+            |(circuit, fired)| (memory(circuit, &counter), *fired),
+        );
 
-#[derive(Debug, Clone)]
-pub struct FFState {
-    state: bool,
-}
+        let fires: Vec<usize> = (cycle.prefix..cycle.prefix + cycle.period)
+            .filter(|&n| cycle.nth(n).1)
+            .collect();
+        debug!(input, cycle.prefix, cycle.period, ?fires, "counter");
 
-impl FFState {
-    pub fn new() -> Self {
-        Self { state: false }
+        let [fire] = fires[..] else {
+            return Err(unsupported(input, feeder));
+        };
+        loops.push((cycle, fire));
     }
-}
 
-#[derive(Debug, Clone)]
-pub struct ConjState {
-    inputs: HashMap<String, bool>,
-}
-
-impl ConjState {
-    pub fn new() -> Self {
-        Self {
-            inputs: HashMap::new(),
-        }
+    // A press before every counter is in its loop has to be checked on its own.
+    let start = loops
+        .iter()
+        .map(|(c, _)| c.prefix)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    if let Some(n) = (1..start).find(|&n| loops.iter().all(|(c, _)| c.nth(n).1)) {
+        return Ok(n);
     }
-}
 
-#[derive(Debug, Clone)]
-pub enum ModuleType {
-    Broadcast,
-    FlipFlop(FFState),
-    Conjunction(ConjState),
-    Output,
+    let (first, period) = graph::crt(
+        loops
+            .iter()
+            .map(|(c, fire)| (*fire as u64, c.period as u64)),
+    )
+    .ok_or_else(|| unsupported(feeder, "rx"))?;
+
+    // The first press on or after the start that every counter fires on.
+    let (first, period) = (first as usize, period as usize);
+    Ok(if first >= start {
+        first
+    } else {
+        first + (start - first).div_ceil(period) * period
+    })
 }
 
-#[derive(Debug, Clone)]
-pub struct Module {
-    name: String,
-    ty: ModuleType,
-}
-
-impl Module {
-    pub fn new(s: &str) -> Self {
-        let first = s.chars().next().unwrap();
+// Every module that can affect what `module` sends, including itself.
+fn upstream(circuit: &Circuit, module: &str) -> BTreeSet<String> {
+    let mut found = BTreeSet::from([module.to_string()]);
+    let mut queue = VecDeque::from([module.to_string()]);
 
-        match first {
-            '%' => Self {
-                name: s.split_at(1).1.to_string(),
-                ty: ModuleType::FlipFlop(FFState::new()),
-            },
-            '&' => Self {
-                name: s.split_at(1).1.to_string(),
-                ty: ModuleType::Conjunction(ConjState::new()),
-            },
-            _ => {
-                if s == "broadcaster" {
-                    Self {
-                        name: s.to_string(),
-                        ty: ModuleType::Broadcast,
-                    }
-                } else {
-                    unreachable!()
-                }
+    while let Some(m) = queue.pop_front() {
+        for i in circuit.inputs.get(&m).into_iter().flatten() {
+            if found.insert(i.clone()) {
+                queue.push_back(i.clone());
             }
         }
     }
-}
-
-#[derive(Debug, Clone)]
-pub struct Event {
-    from: String,
-    target: String,
-    pulse: bool,
-}
-
-pub struct Circuit {
-    modules: HashMap<String, Module>,
-    outputs: HashMap<String, Vec<String>>,
-    inputs: HashMap<String, Vec<String>>,
 
-    high: i64,
-    low: i64,
+    found
 }
 
-impl Circuit {
-    pub fn new() -> Self {
-        Self {
-            modules: HashMap::new(),
-            outputs: HashMap::new(),
-            inputs: HashMap::new(),
-            high: 0,
-            low: 0,
-        }
-    }
-
-    pub fn add_module(&mut self, l: &str) {
-        let (name, targets) = l.split_once("->").unwrap();
-
-        let name = name.trim();
-        let targets = targets.trim();
-
-        let module = Module::new(name);
-        self.modules.insert(module.name.clone(), module.clone());
-
-        let targets = targets.split(", ");
-
-        for t in targets {
-            let (from, to) = (&module.name, t);
-            if !self.outputs.contains_key(from) {
-                self.outputs.insert(from.to_string(), Vec::new());
-            }
-            if !self.inputs.contains_key(to) {
-                self.inputs.insert(to.to_string(), Vec::new());
-            }
-
-            self.outputs.get_mut(from).unwrap().push(to.to_string());
-            self.inputs.get_mut(to).unwrap().push(from.to_string());
-        }
-    }
-
-    pub fn post_init(&mut self) {
-        for (name, v) in self.modules.iter_mut() {
-            if let ModuleType::Conjunction(ref mut state) = &mut v.ty {
-                let inputs = self.inputs.get(name).unwrap();
-                for i in inputs {
-                    state.inputs.insert(i.clone(), false);
-                }
-            }
-
-            if !self.outputs.contains_key(name) {
-                self.outputs.insert(name.clone(), Vec::new());
-            }
-
-            if !self.inputs.contains_key(name) {
-                self.inputs.insert(name.clone(), Vec::new());
+// What each of `modules` remembers between presses.
+fn memory(circuit: &Circuit, modules: &BTreeSet<String>) -> Vec<bool> {
+    let mut memory = Vec::new();
+
+    for m in modules {
+        match circuit.modules.get(m).map(|m| &m.ty) {
+            Some(ModuleType::FlipFlop(ff)) => memory.push(ff.state),
+            Some(ModuleType::Conjunction(conj)) => {
+                let mut inputs: Vec<_> = conj.inputs.iter().collect();
+                inputs.sort();
+                memory.extend(inputs.into_iter().map(|(_, &pulse)| pulse));
             }
+            _ => {}
         }
-
-        self.modules.insert(
-            "output".to_string(),
-            Module {
-                name: "output".to_string(),
-                ty: ModuleType::Output,
-            },
-        );
     }
 
-    pub fn propagate_one(&mut self, event: &Event, iter: usize) -> Vec<Event> {
-        // println!("PROPAGATING:");
-        // println!("{} -> {}: {}", event.from, event.target, event.pulse);
-        if event.pulse {
-            self.high += 1;
-        } else {
-            self.low += 1;
-        }
-        let mut events = Vec::new();
-
-        if event.from == "st" || event.from == "hh" || event.from == "tn" || event.from == "dt" {
-            if event.pulse {
-                println!("#{}: {} {}", iter, event.from, event.pulse);
-            }
-        }
-
-        if !self.modules.contains_key(&event.target) {
-            return Vec::new();
-        }
-
-        let target = self.modules.get_mut(&event.target).unwrap();
-        match &mut target.ty {
-            ModuleType::Broadcast => {
-                for o in self.outputs.get(&target.name).unwrap() {
-                    events.push(Event {
-                        from: target.name.to_string(),
-                        target: o.to_string(),
-                        pulse: event.pulse,
-                    })
-                }
-            }
-            ModuleType::FlipFlop(ref mut state) => {
-                if !event.pulse {
-                    state.state = !state.state;
-                    for o in self.outputs.get(&target.name).unwrap() {
-                        events.push(Event {
-                            from: target.name.to_string(),
-                            target: o.to_string(),
-                            pulse: state.state,
-                        })
-                    }
-                }
-            }
-            ModuleType::Conjunction(ref mut state) => {
-                state.inputs.insert(event.from.clone(), event.pulse);
-
-                let output = !state.inputs.values().all(|b| *b == true);
-
-                for o in self.outputs.get(&target.name).unwrap() {
-                    events.push(Event {
-                        from: target.name.to_string(),
-                        target: o.to_string(),
-                        pulse: output,
-                    })
-                }
-            }
-            ModuleType::Output => {}
-        }
-
-        events
-    }
+    memory
 }
 
-pub fn p2() -> std::io::Result<()> {
-    let file = File::open("d20/src/input.txt")?;
-    let buf_reader = BufReader::new(file);
-
-    let mut circuit = Circuit::new();
-
-    for l in buf_reader.lines() {
-        let l = l?;
-        circuit.add_module(&l);
-    }
-
-    circuit.post_init();
-
-    for i in 0..1000000000 {
-        let mut queue: VecDeque<Event> = VecDeque::new();
-        queue.push_back(Event {
-            from: "button".to_string(),
-            target: "broadcaster".to_string(),
-            pulse: false,
-        });
-
-        while let Some(event) = queue.pop_front() {
-            let result = circuit.propagate_one(&event, i);
-            for r in result {
-                queue.push_back(r);
-            }
-        }
-    }
-
-    println!("{}", circuit.low);
-    println!("{}", circuit.high);
-    println!("{}", circuit.low * circuit.high);
-
-    Ok(())
+fn unsupported(module: &str, target: &str) -> ParseError {
+    let mut error = ParseError::at(
+        "",
+        format!("{module} to send {target} a high pulse once a loop"),
+    );
+    error.found = "a circuit that doesn't line up".to_string();
+    error
 }
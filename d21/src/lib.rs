@@ -0,0 +1,106 @@
+use std::collections::{HashMap, VecDeque};
+
+use common::{ParseError, ParseResult, Solution};
+use grid::{
+    render::{Frames, Picture},
+    Coord, Grid,
+};
+
+mod p1;
+mod p2;
+
+pub const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+pub struct Garden {
+    b: Grid<char>,
+    start: Coord,
+}
+
+impl Garden {
+    fn get(&self, coord: Coord, wrap: bool) -> Option<&char> {
+        if wrap {
+            self.b.get_wrapped(coord)
+        } else {
+            self.b.get(coord)
+        }
+    }
+
+    // Rocks in grey, and the plots reached so far in green: bright for the ones the elf could be
+    // on after `steps`, dim for the others.
+    fn picture(&self, dists: &HashMap<Coord, i64>, steps: i64) -> Picture {
+        let mut picture = Picture::of(&self.b, |c| match c {
+            '#' => [90, 90, 90],
+            _ => [0, 0, 0],
+        });
+        let (ends, passes): (Vec<_>, Vec<_>) = dists.iter().partition(|(_, d)| *d % 2 == steps % 2);
+        picture.paint(passes.into_iter().map(|(c, _)| *c), [20, 80, 20]);
+        picture.paint(ends.into_iter().map(|(c, _)| *c), [60, 220, 60]);
+
+        picture
+    }
+
+    // Number of plots the elf could be standing on after exactly `steps` steps. If `wrap` is set,
+    // the garden repeats infinitely in every direction. Adds a frame to `frames` for every step
+    // the search takes.
+    pub fn reachable(&self, steps: i64, wrap: bool, mut frames: Option<&mut Frames>) -> usize {
+        let mut dists: HashMap<Coord, i64> = HashMap::new();
+        let mut queue: VecDeque<Coord> = VecDeque::new();
+
+        dists.insert(self.start, 0);
+        queue.push_back(self.start);
+
+        let mut drawn = -1;
+        while let Some(c) = queue.pop_front() {
+            let dist = dists[&c];
+
+            // The queue is in order of distance, so everything nearer has been found.
+            if let Some(frames) = frames.as_deref_mut() {
+                if dist > drawn {
+                    frames.push(&self.picture(&dists, steps));
+                    drawn = dist;
+                }
+            }
+
+            if dist == steps {
+                continue;
+            }
+
+            for n in c.neighbors4() {
+                if let Some(c) = self.get(n, wrap) {
+                    if *c != '#' && !dists.contains_key(&n) {
+                        dists.insert(n, dist + 1);
+                        queue.push_back(n);
+                    }
+                }
+            }
+        }
+
+        // We can always step back and forth, so any plot reached with the right parity counts.
+        dists.values().filter(|d| *d % 2 == steps % 2).count()
+    }
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Model = Garden;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> ParseResult<Self::Model> {
+        let b = Grid::parse(input, |c| ".#S".contains(c).then_some(c), "'.', '#' or 'S'")?;
+        let start = b
+            .position(|c| *c == 'S')
+            .ok_or_else(|| ParseError::after(input.trim_end(), "a start tile 'S'"))?;
+
+        Ok(Garden { b, start })
+    }
+
+    fn part1(&self, garden: &Self::Model) -> usize {
+        p1::p1(garden)
+    }
+
+    fn part2(&self, garden: &Self::Model) -> usize {
+        p2::p2(garden)
+    }
+}
@@ -0,0 +1,155 @@
+use std::collections::HashSet;
+
+use common::{Generate, Rng, Solution};
+
+use crate::Day22;
+
+type Brick = Vec<[i64; 3]>;
+
+fn bricks(input: &str) -> Option<Vec<Brick>> {
+    input
+        .lines()
+        .map(|l| {
+            let (a, b) = l.split_once('~')?;
+            let end = |s: &str| -> Option<[i64; 3]> {
+                let v: Vec<i64> = s
+                    .split(',')
+                    .map(|n| n.parse().ok())
+                    .collect::<Option<_>>()?;
+                v.try_into().ok()
+            };
+            let (a, b) = (end(a)?, end(b)?);
+
+            let mut cubes = vec![a];
+            let mut at = a;
+            while at != b {
+                for (i, c) in at.iter_mut().enumerate() {
+                    *c += (b[i] - *c).signum();
+                }
+                cubes.push(at);
+            }
+
+            Some(cubes)
+        })
+        .collect()
+}
+
+fn lowered(brick: &Brick) -> Brick {
+    brick.iter().map(|&[x, y, z]| [x, y, z - 1]).collect()
+}
+
+// Whether `brick` could drop one step, given everything else that's filled.
+fn can_drop(brick: &Brick, filled: &HashSet<[i64; 3]>) -> bool {
+    lowered(brick)
+        .iter()
+        .all(|c| c[2] >= 1 && (brick.contains(c) || !filled.contains(c)))
+}
+
+// Drop bricks one step at a time until none can move, returning how many bricks moved.
+fn settle(bricks: &mut [Brick]) -> usize {
+    let mut filled: HashSet<[i64; 3]> = bricks.iter().flatten().copied().collect();
+    let mut moved = vec![false; bricks.len()];
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for (i, b) in bricks.iter_mut().enumerate() {
+            if can_drop(b, &filled) {
+                for c in b.iter() {
+                    filled.remove(c);
+                }
+                *b = lowered(b);
+                filled.extend(b.iter().copied());
+                moved[i] = true;
+                changed = true;
+            }
+        }
+    }
+
+    moved.iter().filter(|m| **m).count()
+}
+
+fn settled(input: &str) -> Option<Vec<Brick>> {
+    Day22.parse(input).ok()?;
+    let mut bricks = bricks(input)?;
+    settle(&mut bricks);
+
+    Some(bricks)
+}
+
+// Every settled stack with one brick taken out.
+fn without_each(bricks: &[Brick]) -> impl Iterator<Item = Vec<Brick>> + '_ {
+    (0..bricks.len()).map(|i| {
+        let mut rest = bricks.to_vec();
+        rest.remove(i);
+        rest
+    })
+}
+
+impl Generate for Day22 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let side = size.max(2) as i64;
+        let mut filled: HashSet<[i64; 3]> = HashSet::new();
+        let mut lines = Vec::new();
+
+        while lines.len() < size.max(1) * 2 {
+            let start = [
+                rng.range(0..=side - 1),
+                rng.range(0..=side - 1),
+                rng.range(1..=side * 3),
+            ];
+            let axis = rng.index(3);
+            let mut end = start;
+            end[axis] += rng.range(0..=2);
+            if end[0] >= side || end[1] >= side {
+                continue;
+            }
+
+            let brick: Brick = (0..=end[axis] - start[axis])
+                .map(|d| {
+                    let mut c = start;
+                    c[axis] += d;
+                    c
+                })
+                .collect();
+            if brick.iter().any(|c| filled.contains(c)) {
+                continue;
+            }
+
+            filled.extend(brick.iter().copied());
+            // Either end can come first.
+            let (a, b) = if rng.chance(0.5) {
+                (start, end)
+            } else {
+                (end, start)
+            };
+            let coords = |c: [i64; 3]| format!("{},{},{}", c[0], c[1], c[2]);
+            lines.push(format!("{}~{}", coords(a), coords(b)));
+        }
+
+        lines.join("\n") + "\n"
+    }
+
+    fn reference1(input: &str) -> Option<usize> {
+        let bricks = settled(input)?;
+
+        let safe = without_each(&bricks)
+            .filter(|rest| {
+                let filled: HashSet<[i64; 3]> = rest.iter().flatten().copied().collect();
+                !rest.iter().any(|b| can_drop(b, &filled))
+            })
+            .count();
+
+        Some(safe)
+    }
+
+    fn reference2(input: &str) -> Option<usize> {
+        let bricks = settled(input)?;
+
+        Some(
+            without_each(&bricks)
+                .map(|mut rest| settle(&mut rest))
+                .sum(),
+        )
+    }
+}
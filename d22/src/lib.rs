@@ -0,0 +1,324 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    ops,
+};
+
+use common::{
+    parse::{labelled, signed, tag, Parsed, Parser},
+    ParseError, ParseResult, Solution,
+};
+
+mod gen;
+mod p1;
+mod p2;
+
+pub const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Coord {
+    x: i64,
+    y: i64,
+    z: i64,
+}
+
+impl Coord {
+    // "1,0,1"
+    pub fn parse(s: &str) -> Parsed<'_, Self> {
+        let (x, rest) = signed(s)?;
+        let (y, rest) = labelled(",", signed).parse(rest)?;
+        let (z, rest) = labelled(",", signed).parse(rest)?;
+
+        Ok((Self { x, y, z }, rest))
+    }
+
+    pub fn clamp(&self, c: i64) -> Self {
+        let c = c.abs();
+        Self {
+            x: self.x.clamp(-c, c),
+            y: self.y.clamp(-c, c),
+            z: self.z.clamp(-c, c),
+        }
+    }
+
+    pub fn in_world(&self) -> bool {
+        self.z >= 0
+    }
+}
+
+impl Display for Coord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+impl ops::Add<Coord> for Coord {
+    type Output = Coord;
+
+    fn add(self, rhs: Coord) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+        }
+    }
+}
+
+impl ops::AddAssign<Coord> for Coord {
+    fn add_assign(&mut self, rhs: Coord) {
+        *self = *self + rhs
+    }
+}
+
+impl ops::Sub<Coord> for Coord {
+    type Output = Coord;
+
+    fn sub(self, rhs: Coord) -> Self::Output {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+
+pub struct BlockPosIterator {
+    current: Coord,
+    end: Coord,
+    dir: Coord,
+}
+
+impl BlockPosIterator {
+    pub fn new(b: &Block) -> Self {
+        Self {
+            current: b.c1,
+            end: b.c2 + b.dir,
+            dir: b.dir,
+        }
+    }
+}
+
+impl Iterator for BlockPosIterator {
+    type Item = Coord;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current != self.end {
+            let ret = Some(self.current);
+            self.current += self.dir;
+            ret
+        } else {
+            None
+        }
+    }
+}
+#[derive(Clone, Copy)]
+pub struct Block {
+    c1: Coord,
+    c2: Coord,
+    dir: Coord,
+    id: usize,
+}
+
+const ZERO: Coord = Coord { x: 0, y: 0, z: 0 };
+
+impl Block {
+    pub fn new(s: &str, id: usize) -> ParseResult<Self> {
+        let (c1, rest) = Coord::parse(s)?;
+        let (_, c2_str) = tag("~").parse(rest)?;
+        let c2 = Coord::parse.all(c2_str)?;
+
+        let delta = c2 - c1;
+
+        // Blocks are a straight line of cubes, so the ends can only differ along one axis.
+        if [delta.x, delta.y, delta.z]
+            .iter()
+            .filter(|d| **d != 0)
+            .count()
+            > 1
+        {
+            return Err(ParseError::at(c2_str, "an end in line with the start"));
+        }
+
+        let mut dir = delta.clamp(1);
+
+        // Assign an arbitrary direction to one cube blocks
+        if delta == ZERO {
+            dir = Coord { x: 1, y: 0, z: 0 };
+        }
+
+        Ok(Self { c1, c2, dir, id })
+    }
+
+    pub fn coords(&self) -> BlockPosIterator {
+        BlockPosIterator::new(self)
+    }
+
+    pub fn min_z(&self) -> i64 {
+        if self.dir.z == -1 {
+            self.c2.z
+        } else {
+            self.c1.z
+        }
+    }
+}
+
+impl Display for Block {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "#{} {} - {} [dir: {}]",
+            self.id, self.c1, self.c2, self.dir
+        )
+    }
+}
+
+pub struct World {
+    blocks: Vec<Block>,
+    occupancy: HashMap<Coord, usize>,
+    support: HashMap<usize, HashSet<usize>>,
+}
+
+impl Default for World {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+const DOWN: Coord = Coord { x: 0, y: 0, z: -1 };
+
+impl World {
+    pub fn new() -> Self {
+        Self {
+            blocks: Vec::new(),
+            occupancy: HashMap::new(),
+            support: HashMap::new(),
+        }
+    }
+
+    pub fn add_block(&mut self, s: &str) -> ParseResult<()> {
+        let id = self.blocks.len();
+        let b = Block::new(s, id)?;
+
+        for c in b.coords() {
+            self.occupancy.insert(c, id);
+        }
+
+        self.blocks.push(b);
+
+        Ok(())
+    }
+
+    pub fn move_block(&mut self, idx: usize, delta: Coord) {
+        let b = self.blocks.get(idx).unwrap();
+        let mut moved = *b;
+
+        moved.c1 += delta;
+        moved.c2 += delta;
+
+        // Recalculate occupancy
+        for c in b.coords() {
+            self.occupancy.remove(&c);
+        }
+
+        for c in moved.coords() {
+            let present = self.occupancy.insert(c, idx).is_some();
+            // Do not move a block where one already exists!
+            assert!(!present);
+        }
+
+        *self.blocks.get_mut(idx).unwrap() = moved;
+    }
+
+    pub fn sim_block(&mut self, idx: usize) {
+        let b = self.blocks.get(idx).unwrap();
+        let mut coords: Vec<Coord> = b.coords().collect();
+
+        let mut dist = 0;
+        'outer: loop {
+            for c in &mut coords {
+                *c += DOWN;
+                if !c.in_world() {
+                    break 'outer;
+                }
+                if let Some(other) = self.occupancy.get(c) {
+                    // Self-collisions don't count
+                    if *other != idx {
+                        break 'outer;
+                    }
+                }
+            }
+            dist += 1;
+        }
+
+        // Optimization: don't bother editing anything if we haven't moved
+        if dist == 0 {
+            return;
+        }
+
+        self.move_block(
+            idx,
+            Coord {
+                x: 0,
+                y: 0,
+                z: -dist,
+            },
+        );
+    }
+
+    pub fn sim(&mut self) {
+        // Sort bottom up so we simulate properly
+        let mut sorted = self.blocks.clone();
+        sorted.sort_by_key(|b| b.min_z());
+
+        for s in sorted {
+            self.sim_block(s.id);
+        }
+    }
+
+    pub fn calculate_support(&mut self) {
+        for b in &self.blocks {
+            // println!("{b}");
+            let mut support: HashSet<usize> = HashSet::new();
+            for c in b.coords() {
+                let under = c + DOWN;
+                // println!("{under}");
+                if let Some(under_block) = self.occupancy.get(&under) {
+                    // println!("Found supporting block for {}: {}", b.id, under_block);
+                    if *under_block != b.id {
+                        support.insert(*under_block);
+                    }
+                }
+            }
+            self.support.insert(b.id, support);
+        }
+    }
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Model = World;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> ParseResult<Self::Model> {
+        let mut w = World::new();
+
+        for l in input.lines() {
+            w.add_block(l)?;
+        }
+
+        w.sim();
+        w.calculate_support();
+
+        Ok(w)
+    }
+
+    fn part1(&self, world: &Self::Model) -> usize {
+        p1::p1(world)
+    }
+
+    fn part2(&self, world: &Self::Model) -> usize {
+        p2::p2(world)
+    }
+}
@@ -1,13 +1,29 @@
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-};
+use std::collections::HashSet;
 
-pub fn p2() -> std::io::Result<()> {
-    let file = File::open("d22/src/input.txt")?;
-    let buf_reader = BufReader::new(file);
+use crate::{Block, World};
 
-    for l in buf_reader.lines() {}
+// Number of other blocks that fall if `removed` is disintegrated.
+fn chain_reaction(w: &World, sorted: &[Block], removed: usize) -> usize {
+    let mut fallen: HashSet<usize> = HashSet::new();
+    fallen.insert(removed);
 
-    Ok(())
+    // Bottom up, so every block's supports have already been decided.
+    for b in sorted {
+        let support = &w.support[&b.id];
+        if !support.is_empty() && support.is_subset(&fallen) {
+            fallen.insert(b.id);
+        }
+    }
+
+    fallen.len() - 1
+}
+
+pub fn p2(w: &World) -> usize {
+    let mut sorted = w.blocks.clone();
+    sorted.sort_by_key(|b| b.min_z());
+
+    w.blocks
+        .iter()
+        .map(|b| chain_reaction(w, &sorted, b.id))
+        .sum()
 }
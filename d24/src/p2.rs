@@ -1,13 +1,5 @@
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-};
+use common::Unsolved;
 
-pub fn p2() -> std::io::Result<()> {
-    let file = File::open("d24/src/input.txt")?;
-    let buf_reader = BufReader::new(file);
-
-    for l in buf_reader.lines() {}
-
-    Ok(())
+pub fn p2(_lines: &[String]) -> Unsolved {
+    Unsolved
 }
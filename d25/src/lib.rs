@@ -0,0 +1,27 @@
+use common::{ParseResult, Solution, Unsolved};
+
+mod p1;
+mod p2;
+
+pub const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+// https://adventofcode.com/2023/day/25
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Model = Vec<String>;
+    type Part1 = Unsolved;
+    type Part2 = Unsolved;
+
+    fn parse(&self, input: &str) -> ParseResult<Self::Model> {
+        Ok(input.lines().map(|l| l.to_string()).collect())
+    }
+
+    fn part1(&self, lines: &Self::Model) -> Unsolved {
+        p1::p1(lines)
+    }
+
+    fn part2(&self, lines: &Self::Model) -> Unsolved {
+        p2::p2(lines)
+    }
+}
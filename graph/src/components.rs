@@ -0,0 +1,169 @@
+use std::collections::HashMap;
+
+use crate::Graph;
+
+/// The strongly connected components of the part of `graph` reachable from `roots`: groups of
+/// nodes that can all reach each other.
+///
+/// Components come out in reverse topological order, so edges out of a component only go to it
+/// or to components before it.
+pub fn strongly_connected<G: Graph>(
+    graph: &G,
+    roots: impl IntoIterator<Item = G::Node>,
+) -> Vec<Vec<G::Node>> {
+    // Tarjan's algorithm, with an explicit stack so deep graphs can't overflow the real one.
+    // Nodes are numbered in the order they're found.
+    let mut index: HashMap<G::Node, usize> = HashMap::new();
+    let mut nodes: Vec<G::Node> = Vec::new();
+    let mut low: Vec<usize> = Vec::new();
+    let mut on_stack: Vec<bool> = Vec::new();
+    let mut stack: Vec<usize> = Vec::new();
+    let mut components = Vec::new();
+
+    for root in roots {
+        if index.contains_key(&root) {
+            continue;
+        }
+
+        // Each frame is a node being visited, its neighbors, and how many of them we've done.
+        let mut frames: Vec<(usize, Vec<G::Node>, usize)> = Vec::new();
+        let mut visit = Some(root);
+
+        loop {
+            if let Some(node) = visit.take() {
+                let id = nodes.len();
+                let neighbors = graph.neighbors(&node).into_iter().collect();
+                index.insert(node.clone(), id);
+                nodes.push(node);
+                low.push(id);
+                on_stack.push(true);
+                stack.push(id);
+                frames.push((id, neighbors, 0));
+            }
+
+            let Some((id, neighbors, done)) = frames.last_mut() else {
+                break;
+            };
+            let id = *id;
+
+            if let Some(next) = neighbors.get(*done) {
+                *done += 1;
+                match index.get(next) {
+                    None => visit = Some(next.clone()),
+                    Some(&n) if on_stack[n] => low[id] = low[id].min(n),
+                    Some(_) => {}
+                }
+                continue;
+            }
+
+            frames.pop();
+            if let Some((parent, _, _)) = frames.last() {
+                low[*parent] = low[*parent].min(low[id]);
+            }
+
+            // Nothing below this node reaches above it, so it roots a component.
+            if low[id] == id {
+                let mut component = Vec::new();
+                while let Some(n) = stack.pop() {
+                    on_stack[n] = false;
+                    component.push(nodes[n].clone());
+                    if n == id {
+                        break;
+                    }
+                }
+                components.push(component);
+            }
+        }
+    }
+
+    components
+}
+
+/// The nodes reachable from `roots`, ordered so every edge goes from an earlier node to a later
+/// one. If there's a cycle there's no such order, and the nodes of a component with a cycle in it
+/// are returned instead.
+pub fn toposort<G: Graph>(
+    graph: &G,
+    roots: impl IntoIterator<Item = G::Node>,
+) -> Result<Vec<G::Node>, Vec<G::Node>> {
+    let mut order = Vec::new();
+
+    for component in strongly_connected(graph, roots).into_iter().rev() {
+        let node = &component[0];
+        if component.len() > 1 || graph.neighbors(node).into_iter().any(|n| n == *node) {
+            return Err(component);
+        }
+
+        order.extend(component);
+    }
+
+    Ok(order)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{strongly_connected, toposort};
+
+    fn graph(edges: &[(char, &str)]) -> HashMap<char, Vec<char>> {
+        edges
+            .iter()
+            .map(|&(from, to)| (from, to.chars().collect()))
+            .collect()
+    }
+
+    fn sorted(mut components: Vec<Vec<char>>) -> Vec<Vec<char>> {
+        for c in &mut components {
+            c.sort();
+        }
+        components
+    }
+
+    #[test]
+    fn strongly_connected_cycle() {
+        // a -> b -> c -> a is a cycle, and d hangs off it.
+        let g = graph(&[('a', "b"), ('b', "c"), ('c', "ad"), ('d', "")]);
+
+        // d has no way back, so it comes out first.
+        assert_eq!(
+            sorted(strongly_connected(&g, ['a'])),
+            [vec!['d'], vec!['a', 'b', 'c']]
+        );
+        // Only what's reachable from the roots.
+        assert_eq!(sorted(strongly_connected(&g, ['d'])), [vec!['d']]);
+    }
+
+    #[test]
+    fn strongly_connected_dag() {
+        let g = graph(&[('a', "bc"), ('b', "d"), ('c', "d"), ('d', "")]);
+        let components = strongly_connected(&g, ['a']);
+
+        assert_eq!(components.len(), 4);
+        assert!(components.iter().all(|c| c.len() == 1));
+        assert_eq!(components[0], ['d']);
+        assert_eq!(components[3], ['a']);
+    }
+
+    #[test]
+    fn toposort_order() {
+        let g = graph(&[('a', "bc"), ('b', "d"), ('c', "bd"), ('d', "")]);
+
+        assert_eq!(toposort(&g, ['a']), Ok(vec!['a', 'c', 'b', 'd']));
+    }
+
+    #[test]
+    fn toposort_cycles() {
+        let g = graph(&[('a', "b"), ('b', "b")]);
+        assert_eq!(toposort(&g, ['a']), Err(vec!['b']));
+
+        let g = graph(&[('a', "b"), ('b', "ca"), ('c', "")]);
+        assert_eq!(
+            toposort(&g, ['a']).map_err(|mut c| {
+                c.sort();
+                c
+            }),
+            Err(vec!['a', 'b'])
+        );
+    }
+}
@@ -0,0 +1,37 @@
+use std::{collections::HashMap, hash::Hash, ops::Add};
+
+mod components;
+mod number;
+mod search;
+
+pub use components::{strongly_connected, toposort};
+pub use number::{crt, gcd, lcm, lcm_all};
+pub use search::{astar, bfs, dijkstra, Path};
+
+/// A directed graph, given by the neighbors of each node.
+///
+/// Nodes don't have to be stored anywhere, so a graph can just as well be the states of a search
+/// that are worked out as it goes.
+pub trait Graph {
+    type Node: Clone + Eq + Hash;
+
+    fn neighbors(&self, node: &Self::Node) -> impl IntoIterator<Item = Self::Node>;
+}
+
+/// A directed graph whose edges have a cost.
+pub trait WeightedGraph {
+    type Node: Clone + Eq + Hash;
+    type Cost: Copy + Ord + Default + Add<Output = Self::Cost>;
+
+    /// The neighbors of `node`, with the cost of moving to each.
+    fn edges(&self, node: &Self::Node) -> impl IntoIterator<Item = (Self::Node, Self::Cost)>;
+}
+
+/// A graph of named nodes, from each node to the ones it has edges to.
+impl<N: Clone + Eq + Hash> Graph for HashMap<N, Vec<N>> {
+    type Node = N;
+
+    fn neighbors(&self, node: &N) -> impl IntoIterator<Item = N> {
+        self.get(node).into_iter().flatten().cloned()
+    }
+}
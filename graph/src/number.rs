@@ -0,0 +1,113 @@
+/// Greatest common divisor.
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+/// Least common multiple, or `None` if it doesn't fit in a `u64`.
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// Least common multiple of every value, or `None` if it doesn't fit in a `u64`. 1 if there are
+/// no values.
+pub fn lcm_all(values: impl IntoIterator<Item = u64>) -> Option<u64> {
+    values.into_iter().try_fold(1, lcm)
+}
+
+// Returns (g, x) where g = gcd(a, m) and a * x = g (mod m).
+fn extended_gcd(a: i128, m: i128) -> (i128, i128) {
+    let (mut old_r, mut r) = (a, m);
+    let (mut old_x, mut x) = (1, 0);
+
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+    }
+
+    (old_r, old_x)
+}
+
+/// Solve a system of congruences `x = remainder (mod modulus)` with the Chinese remainder theorem.
+/// The moduli don't have to be coprime.
+///
+/// Returns the smallest solution and the period every solution repeats with, or `None` if there
+/// is no solution, a modulus is zero, or the period doesn't fit in a `u64`.
+pub fn crt(congruences: impl IntoIterator<Item = (u64, u64)>) -> Option<(u64, u64)> {
+    let (mut x, mut period) = (0_i128, 1_i128);
+
+    for (remainder, modulus) in congruences {
+        if modulus == 0 {
+            return None;
+        }
+        let (remainder, modulus) = (remainder as i128 % modulus as i128, modulus as i128);
+
+        // Solve x + period * k = remainder (mod modulus) for k.
+        let (g, inverse) = extended_gcd(period, modulus);
+        let diff = remainder - x;
+        if diff % g != 0 {
+            return None;
+        }
+
+        let step = modulus / g;
+        let next_period = period
+            .checked_mul(step)
+            .filter(|p| *p <= u64::MAX as i128)?;
+
+        // Both factors are below `step`, which fits in a u64, so their product fits in a u128.
+        let a = (diff / g).rem_euclid(step) as u128;
+        let b = inverse.rem_euclid(step) as u128;
+        let k = (a * b % step as u128) as i128;
+
+        x = (x + period * k).rem_euclid(next_period);
+        period = next_period;
+    }
+
+    Some((x as u64, period as u64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{crt, lcm, lcm_all};
+
+    #[test]
+    fn crt_coprime() {
+        // x = 2 (mod 3), x = 3 (mod 5), x = 2 (mod 7)
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([]), Some((0, 1)));
+    }
+
+    #[test]
+    fn crt_not_coprime() {
+        // The period is the lcm of the moduli, not their product.
+        assert_eq!(crt([(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt([(3, 6), (3, 6)]), Some((3, 6)));
+        // Remainders that disagree on the common factor.
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt([(1, 0)]), None);
+    }
+
+    #[test]
+    fn crt_overflow() {
+        let big = u64::MAX / 2;
+        assert_eq!(crt([(1, big), (1, big)]), Some((1, big)));
+        // Coprime moduli whose product doesn't fit in a u64.
+        assert_eq!(crt([(0, big), (0, big - 2)]), None);
+    }
+
+    #[test]
+    fn lcm_overflow() {
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(u64::MAX, 2), None);
+        assert_eq!(lcm_all([]), Some(1));
+        assert_eq!(lcm_all([1 << 40, 3 << 30, 5 << 20]), Some(15 << 40));
+    }
+}
@@ -0,0 +1,189 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+};
+
+use crate::{Graph, WeightedGraph};
+
+/// The fewest steps from `start` to every node it can reach.
+pub fn bfs<G: Graph>(graph: &G, start: G::Node) -> HashMap<G::Node, u64> {
+    let mut queue = VecDeque::from([start.clone()]);
+    let mut dists = HashMap::from([(start, 0)]);
+
+    while let Some(node) = queue.pop_front() {
+        let dist = dists[&node];
+
+        for next in graph.neighbors(&node) {
+            if !dists.contains_key(&next) {
+                dists.insert(next.clone(), dist + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    dists
+}
+
+/// A cheapest route to a goal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub cost: C,
+    /// Every node along the way, from the start to the goal.
+    pub nodes: Vec<N>,
+}
+
+/// The cheapest route from `start` to any node that `is_goal`, or `None` if there's no way there.
+pub fn dijkstra<G: WeightedGraph>(
+    graph: &G,
+    start: G::Node,
+    is_goal: impl FnMut(&G::Node) -> bool,
+) -> Option<Path<G::Node, G::Cost>> {
+    astar(graph, start, is_goal, |_| G::Cost::default())
+}
+
+/// Like [`dijkstra`], but guided by `heuristic`, an estimate of the cost from a node to the
+/// nearest goal.
+///
+/// The route is only guaranteed to be cheapest if the heuristic never overestimates, and never
+/// drops by more than the cost of an edge.
+pub fn astar<G: WeightedGraph>(
+    graph: &G,
+    start: G::Node,
+    mut is_goal: impl FnMut(&G::Node) -> bool,
+    mut heuristic: impl FnMut(&G::Node) -> G::Cost,
+) -> Option<Path<G::Node, G::Cost>> {
+    // Nodes are numbered as they're found, so the heap doesn't need to order them.
+    let mut index: HashMap<G::Node, usize> = HashMap::from([(start.clone(), 0)]);
+    let mut nodes = vec![start];
+    let mut costs = vec![G::Cost::default()];
+    let mut parents: Vec<Option<usize>> = vec![None];
+
+    let mut heap = BinaryHeap::new();
+    heap.push(Reverse((heuristic(&nodes[0]), G::Cost::default(), 0)));
+
+    while let Some(Reverse((_, cost, idx))) = heap.pop() {
+        // A cheaper way here was found after this one was queued.
+        if cost > costs[idx] {
+            continue;
+        }
+
+        if is_goal(&nodes[idx]) {
+            let mut path = vec![nodes[idx].clone()];
+            let mut at = idx;
+            while let Some(parent) = parents[at] {
+                path.push(nodes[parent].clone());
+                at = parent;
+            }
+            path.reverse();
+
+            return Some(Path { cost, nodes: path });
+        }
+
+        let node = nodes[idx].clone();
+        for (next, step) in graph.edges(&node) {
+            let next_cost = cost + step;
+
+            let next_idx = match index.get(&next) {
+                Some(&i) if costs[i] <= next_cost => continue,
+                Some(&i) => {
+                    costs[i] = next_cost;
+                    parents[i] = Some(idx);
+                    i
+                }
+                None => {
+                    let i = nodes.len();
+                    index.insert(next.clone(), i);
+                    nodes.push(next);
+                    costs.push(next_cost);
+                    parents.push(Some(idx));
+                    i
+                }
+            };
+
+            let estimate = next_cost + heuristic(&nodes[next_idx]);
+            heap.push(Reverse((estimate, next_cost, next_idx)));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{astar, bfs, dijkstra};
+    use crate::WeightedGraph;
+
+    // A grid where stepping onto a cell costs its digit, and '#' can't be stepped on.
+    struct Costs(Vec<Vec<u8>>);
+
+    impl Costs {
+        fn new(rows: &[&str]) -> Self {
+            Self(rows.iter().map(|r| r.bytes().collect()).collect())
+        }
+    }
+
+    impl WeightedGraph for Costs {
+        type Node = (i64, i64);
+        type Cost = u64;
+
+        fn edges(&self, &(row, col): &(i64, i64)) -> impl IntoIterator<Item = ((i64, i64), u64)> {
+            [(-1, 0), (1, 0), (0, -1), (0, 1)]
+                .into_iter()
+                .map(move |(dr, dc)| (row + dr, col + dc))
+                .filter_map(|(r, c)| {
+                    let cell = *self
+                        .0
+                        .get(usize::try_from(r).ok()?)?
+                        .get(usize::try_from(c).ok()?)?;
+                    cell.is_ascii_digit()
+                        .then(|| ((r, c), (cell - b'0') as u64))
+                })
+        }
+    }
+
+    fn manhattan(goal: (i64, i64)) -> impl Fn(&(i64, i64)) -> u64 {
+        move |&(r, c)| r.abs_diff(goal.0) + c.abs_diff(goal.1)
+    }
+
+    #[test]
+    fn astar_detour() {
+        // Going around the 9s is cheaper than through them.
+        let g = Costs::new(&["191", "191", "111"]);
+        let goal = (0, 2);
+
+        let path = astar(&g, (0, 0), |n| *n == goal, manhattan(goal)).unwrap();
+        assert_eq!(path.cost, 6);
+        assert_eq!(
+            path.nodes,
+            [(0, 0), (1, 0), (2, 0), (2, 1), (2, 2), (1, 2), (0, 2)]
+        );
+        assert_eq!(dijkstra(&g, (0, 0), |n| *n == goal).unwrap().cost, 6);
+    }
+
+    #[test]
+    fn astar_unreachable() {
+        let g = Costs::new(&["1#1", "1#1"]);
+        let goal = (0, 2);
+
+        assert_eq!(astar(&g, (0, 0), |n| *n == goal, manhattan(goal)), None);
+    }
+
+    #[test]
+    fn astar_start_is_goal() {
+        let g = Costs::new(&["11"]);
+        let path = astar(&g, (0, 1), |n| *n == (0, 1), manhattan((0, 1))).unwrap();
+
+        assert_eq!((path.cost, path.nodes), (0, vec![(0, 1)]));
+    }
+
+    #[test]
+    fn bfs_steps() {
+        let g = HashMap::from([('a', vec!['b', 'c']), ('b', vec!['d']), ('c', vec!['d'])]);
+        let dists = bfs(&g, 'a');
+
+        assert_eq!(dists.len(), 4);
+        assert_eq!((dists[&'a'], dists[&'b'], dists[&'d']), (0, 1, 2));
+    }
+}
@@ -0,0 +1,86 @@
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Mul, Sub},
+};
+
+use crate::Dir;
+
+/// A position on a grid. Rows grow downwards and columns grow to the right.
+///
+/// Signed, so positions off the edge of a grid (or on an infinite one) can still be represented.
+#[derive(Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Copy)]
+pub struct Coord {
+    pub row: i64,
+    pub col: i64,
+}
+
+const DIAGONALS: [Coord; 4] = [
+    Coord { row: -1, col: -1 },
+    Coord { row: -1, col: 1 },
+    Coord { row: 1, col: 1 },
+    Coord { row: 1, col: -1 },
+];
+
+impl Coord {
+    pub const fn new(row: i64, col: i64) -> Self {
+        Self { row, col }
+    }
+
+    /// The adjacent position in `dir`.
+    pub fn step(&self, dir: Dir) -> Self {
+        *self + dir.delta()
+    }
+
+    /// The four orthogonally adjacent positions, clockwise from up.
+    pub fn neighbors4(&self) -> impl Iterator<Item = Coord> {
+        let c = *self;
+        Dir::ALL.into_iter().map(move |d| c.step(d))
+    }
+
+    /// The eight orthogonally and diagonally adjacent positions.
+    pub fn neighbors8(&self) -> impl Iterator<Item = Coord> {
+        let c = *self;
+        self.neighbors4()
+            .chain(DIAGONALS.into_iter().map(move |d| c + d))
+    }
+
+    pub fn manhattan(&self, other: &Self) -> u64 {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+}
+
+impl Display for Coord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.row, self.col)
+    }
+}
+
+impl Add for Coord {
+    type Output = Coord;
+
+    fn add(self, rhs: Coord) -> Self::Output {
+        Coord::new(self.row + rhs.row, self.col + rhs.col)
+    }
+}
+
+impl AddAssign for Coord {
+    fn add_assign(&mut self, rhs: Coord) {
+        *self = *self + rhs
+    }
+}
+
+impl Sub for Coord {
+    type Output = Coord;
+
+    fn sub(self, rhs: Coord) -> Self::Output {
+        Coord::new(self.row - rhs.row, self.col - rhs.col)
+    }
+}
+
+impl Mul<i64> for Coord {
+    type Output = Coord;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        Coord::new(self.row * rhs, self.col * rhs)
+    }
+}
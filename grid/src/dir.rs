@@ -0,0 +1,58 @@
+use crate::Coord;
+
+/// One of the four orthogonal directions on a grid.
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Copy)]
+pub enum Dir {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir {
+    /// Every direction, clockwise from up.
+    pub const ALL: [Dir; 4] = [Dir::Up, Dir::Right, Dir::Down, Dir::Left];
+
+    /// The offset of one step in this direction.
+    pub fn delta(&self) -> Coord {
+        match self {
+            Dir::Up => Coord::new(-1, 0),
+            Dir::Right => Coord::new(0, 1),
+            Dir::Down => Coord::new(1, 0),
+            Dir::Left => Coord::new(0, -1),
+        }
+    }
+
+    pub fn opposite(&self) -> Dir {
+        match self {
+            Dir::Up => Dir::Down,
+            Dir::Right => Dir::Left,
+            Dir::Down => Dir::Up,
+            Dir::Left => Dir::Right,
+        }
+    }
+
+    /// Turn 90 degrees clockwise.
+    pub fn turn_right(&self) -> Dir {
+        match self {
+            Dir::Up => Dir::Right,
+            Dir::Right => Dir::Down,
+            Dir::Down => Dir::Left,
+            Dir::Left => Dir::Up,
+        }
+    }
+
+    /// Turn 90 degrees counter-clockwise.
+    pub fn turn_left(&self) -> Dir {
+        self.turn_right().opposite()
+    }
+
+    /// Position in [`Dir::ALL`], handy for indexing per-direction arrays.
+    pub fn idx(&self) -> usize {
+        *self as usize
+    }
+
+    pub fn is_vertical(&self) -> bool {
+        matches!(self, Dir::Up | Dir::Down)
+    }
+}
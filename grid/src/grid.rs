@@ -0,0 +1,268 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use common::{
+    parse::{self, Cells, Parser},
+    ParseResult,
+};
+
+use crate::Coord;
+
+/// A dense, rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "Grid cells don't fit bounds!");
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Build a grid from one row per line of text, converting each character with `f`. `expected`
+    /// describes the characters `f` accepts, for the error when it returns `None`.
+    pub fn parse(
+        input: &str,
+        f: impl FnMut(char) -> Option<T>,
+        expected: &'static str,
+    ) -> ParseResult<Self> {
+        let Cells {
+            width,
+            height,
+            cells,
+        } = parse::cells(f, expected).all(input)?;
+
+        Ok(Self::from_vec(width, height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, c: Coord) -> bool {
+        c.row >= 0 && c.col >= 0 && (c.row as usize) < self.height && (c.col as usize) < self.width
+    }
+
+    fn offset(&self, c: Coord) -> Option<usize> {
+        if self.in_bounds(c) {
+            Some(c.row as usize * self.width + c.col as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, c: Coord) -> Option<&T> {
+        self.offset(c).map(|o| &self.cells[o])
+    }
+
+    pub fn get_mut(&mut self, c: Coord) -> Option<&mut T> {
+        self.offset(c).map(|o| &mut self.cells[o])
+    }
+
+    /// Map `c` back into bounds, as if the grid repeated infinitely in every direction. `None` for
+    /// an empty grid, which has no bounds to map into.
+    pub fn wrap(&self, c: Coord) -> Option<Coord> {
+        if self.cells.is_empty() {
+            return None;
+        }
+
+        Some(Coord::new(
+            c.row.rem_euclid(self.height as i64),
+            c.col.rem_euclid(self.width as i64),
+        ))
+    }
+
+    /// Like `get`, but on the infinite tiling of this grid.
+    pub fn get_wrapped(&self, c: Coord) -> Option<&T> {
+        self.wrap(c).map(|c| &self[c])
+    }
+
+    /// In-bounds orthogonal neighbours of `c`.
+    pub fn neighbors4(&self, c: Coord) -> impl Iterator<Item = Coord> + '_ {
+        c.neighbors4().filter(|n| self.in_bounds(*n))
+    }
+
+    /// In-bounds orthogonal and diagonal neighbours of `c`.
+    pub fn neighbors8(&self, c: Coord) -> impl Iterator<Item = Coord> + '_ {
+        c.neighbors8().filter(|n| self.in_bounds(*n))
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn coords(&self) -> impl Iterator<Item = Coord> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| Coord::new((i / width) as i64, (i % width) as i64))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.coords().zip(self.cells.iter())
+    }
+
+    /// Position of the first cell matching `pred`, row by row.
+    pub fn position(&self, pred: impl Fn(&T) -> bool) -> Option<Coord> {
+        self.iter().find(|(_, t)| pred(t)).map(|(c, _)| c)
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks() panics on a zero width.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::from_vec(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self::from_vec(width, height, vec![fill; width * height])
+    }
+
+    /// Swap rows and columns.
+    pub fn transpose(&self) -> Self {
+        let cells = (0..self.width)
+            .flat_map(|col| (0..self.height).map(move |row| (row, col)))
+            .map(|(row, col)| self.cells[row * self.width + col].clone())
+            .collect();
+
+        Self::from_vec(self.height, self.width, cells)
+    }
+
+    /// Rotate a quarter turn clockwise, so the left column becomes the top row.
+    pub fn rotate_cw(&self) -> Self {
+        let cells = (0..self.width)
+            .flat_map(|col| (0..self.height).rev().map(move |row| (row, col)))
+            .map(|(row, col)| self.cells[row * self.width + col].clone())
+            .collect();
+
+        Self::from_vec(self.height, self.width, cells)
+    }
+
+    /// Rotate a quarter turn counter-clockwise, so the top row becomes the left column.
+    pub fn rotate_ccw(&self) -> Self {
+        let cells = (0..self.width)
+            .rev()
+            .flat_map(|col| (0..self.height).map(move |row| (row, col)))
+            .map(|(row, col)| self.cells[row * self.width + col].clone())
+            .collect();
+
+        Self::from_vec(self.height, self.width, cells)
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, c: Coord) -> &Self::Output {
+        self.get(c)
+            .unwrap_or_else(|| panic!("{c} is outside the grid!"))
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, c: Coord) -> &mut Self::Output {
+        self.get_mut(c)
+            .unwrap_or_else(|| panic!("{c} is outside the grid!"))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+    use crate::Coord;
+
+    // abc
+    // def
+    fn grid() -> Grid<char> {
+        Grid::parse("abc\ndef\n", Some, "any character").unwrap()
+    }
+
+    #[test]
+    fn transpose() {
+        let t = grid().transpose();
+
+        assert_eq!((t.width(), t.height()), (2, 3));
+        assert_eq!(t.to_string(), "ad\nbe\ncf\n");
+        assert_eq!(t.transpose(), grid());
+    }
+
+    #[test]
+    fn rotate_cw() {
+        let r = grid().rotate_cw();
+
+        assert_eq!((r.width(), r.height()), (2, 3));
+        assert_eq!(r.to_string(), "da\neb\nfc\n");
+    }
+
+    #[test]
+    fn rotate_ccw() {
+        let r = grid().rotate_ccw();
+
+        assert_eq!((r.width(), r.height()), (2, 3));
+        assert_eq!(r.to_string(), "cf\nbe\nad\n");
+    }
+
+    #[test]
+    fn rotations_round_trip() {
+        let g = grid();
+
+        assert_eq!(g.rotate_cw().rotate_ccw(), g);
+        assert_eq!(g.rotate_ccw().rotate_cw(), g);
+        assert_eq!(g.rotate_cw().rotate_cw().rotate_cw().rotate_cw(), g);
+        assert_eq!(g.rotate_ccw().rotate_ccw().rotate_ccw().rotate_ccw(), g);
+        assert_eq!(g.rotate_cw().rotate_cw(), g.rotate_ccw().rotate_ccw());
+    }
+
+    #[test]
+    fn wrap() {
+        let g = grid();
+
+        assert_eq!(g.wrap(Coord::new(1, 2)), Some(Coord::new(1, 2)));
+        assert_eq!(g.wrap(Coord::new(2, 3)), Some(Coord::new(0, 0)));
+        assert_eq!(g.wrap(Coord::new(-1, -1)), Some(Coord::new(1, 2)));
+        assert_eq!(g.wrap(Coord::new(-5, 7)), Some(Coord::new(1, 1)));
+        assert_eq!(g.get_wrapped(Coord::new(-2, -4)), Some(&'c'));
+    }
+
+    #[test]
+    fn wrap_empty() {
+        let g = Grid::parse("", Some, "any character").unwrap();
+        assert_eq!((g.width(), g.height()), (0, 0));
+        assert_eq!(g.wrap(Coord::new(0, 0)), None);
+        assert_eq!(g.get_wrapped(Coord::new(-1, 3)), None);
+
+        // Rows of no cells are just as empty.
+        let g = Grid::new(0, 3, '.');
+        assert_eq!(g.wrap(Coord::new(1, 1)), None);
+    }
+}
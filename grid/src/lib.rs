@@ -0,0 +1,8 @@
+mod coord;
+mod dir;
+mod grid;
+pub mod render;
+
+pub use coord::Coord;
+pub use dir::Dir;
+pub use grid::Grid;
@@ -0,0 +1,189 @@
+use std::{
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+use tracing::{info, warn};
+
+use crate::{Coord, Grid};
+
+pub type Rgb = [u8; 3];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Png,
+    Ppm,
+}
+
+impl ImageFormat {
+    fn extension(&self) -> &'static str {
+        match self {
+            Self::Png => "png",
+            Self::Ppm => "ppm",
+        }
+    }
+}
+
+// Pictures are scaled up to about this many pixels across, so small grids aren't tiny.
+const TARGET_SIZE: usize = 800;
+
+static OUTPUT: OnceLock<(PathBuf, ImageFormat)> = OnceLock::new();
+
+/// Save pictures under `dir` from now on. Until this is called, rendering is off and saving a
+/// picture does nothing.
+pub fn render_to(dir: PathBuf, format: ImageFormat) {
+    if OUTPUT.set((dir, format)).is_err() {
+        panic!("Already rendering somewhere else");
+    }
+}
+
+/// Whether pictures are being saved, to skip building them when they're not.
+pub fn enabled() -> bool {
+    OUTPUT.get().is_some()
+}
+
+/// An image of a grid, one block of pixels per cell.
+#[derive(Debug, Clone)]
+pub struct Picture {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Picture {
+    pub fn new(width: usize, height: usize, background: Rgb) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![background; width * height],
+        }
+    }
+
+    /// A picture of `grid`, coloring each cell with `color`.
+    pub fn of<T>(grid: &Grid<T>, mut color: impl FnMut(&T) -> Rgb) -> Self {
+        Self {
+            width: grid.width(),
+            height: grid.height(),
+            pixels: grid.iter().map(|(_, t)| color(t)).collect(),
+        }
+    }
+
+    /// Color `cells` over whatever is there, for showing a path, a visited set and so on. Cells
+    /// outside the picture are skipped.
+    pub fn paint(&mut self, cells: impl IntoIterator<Item = Coord>, color: Rgb) {
+        for c in cells {
+            if (0..self.height as i64).contains(&c.row) && (0..self.width as i64).contains(&c.col) {
+                self.pixels[c.row as usize * self.width + c.col as usize] = color;
+            }
+        }
+    }
+
+    /// Save as `name` in the render directory, if rendering is on.
+    pub fn save(&self, name: &str) {
+        let Some((dir, format)) = OUTPUT.get() else {
+            return;
+        };
+
+        let path = dir.join(name).with_extension(format.extension());
+        match self.write(&path, *format) {
+            Ok(()) => info!("rendered {}", path.display()),
+            Err(e) => warn!("{}: {e}", path.display()),
+        }
+    }
+
+    fn scale(&self) -> usize {
+        (TARGET_SIZE / self.width.max(self.height).max(1)).max(1)
+    }
+
+    // Every pixel row by row, each cell blown up to a `scale` pixel square.
+    fn rows(&self) -> impl Iterator<Item = u8> + '_ {
+        let scale = self.scale();
+
+        self.pixels.chunks(self.width.max(1)).flat_map(move |row| {
+            (0..scale).flat_map(move |_| {
+                row.iter()
+                    .flat_map(move |rgb| (0..scale).flat_map(move |_| *rgb))
+            })
+        })
+    }
+
+    fn write(&self, path: &Path, format: ImageFormat) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let scale = self.scale();
+        let (width, height) = (self.width * scale, self.height * scale);
+        let mut out = BufWriter::new(File::create(path)?);
+
+        match format {
+            ImageFormat::Ppm => {
+                write!(out, "P6\n{width} {height}\n255\n")?;
+                out.write_all(&self.rows().collect::<Vec<u8>>())?;
+            }
+            ImageFormat::Png => {
+                let mut encoder = png::Encoder::new(&mut out, width as u32, height as u32);
+                encoder.set_color(png::ColorType::Rgb);
+                encoder.set_depth(png::BitDepth::Eight);
+                encoder
+                    .write_header()?
+                    .write_image_data(&self.rows().collect::<Vec<u8>>())?;
+            }
+        }
+
+        out.flush()
+    }
+}
+
+/// A numbered sequence of pictures, for watching a simulation run. Stitch them into an animation
+/// with something like `ffmpeg -i frame-%05d.png`.
+pub struct Frames {
+    dir: PathBuf,
+    format: ImageFormat,
+    count: usize,
+}
+
+impl Frames {
+    /// Start a sequence in the `name` directory under the render directory, replacing any frames
+    /// already there. `None` if rendering is off.
+    pub fn new(name: &str) -> Option<Self> {
+        let (dir, format) = OUTPUT.get()?;
+        let dir = dir.join(name);
+
+        // Leftovers from a longer run would look like part of this one.
+        if let Ok(entries) = fs::read_dir(&dir) {
+            for e in entries.flatten() {
+                if e.file_name().to_string_lossy().starts_with("frame-") {
+                    let _ = fs::remove_file(e.path());
+                }
+            }
+        }
+
+        Some(Self {
+            dir,
+            format: *format,
+            count: 0,
+        })
+    }
+
+    pub fn push(&mut self, picture: &Picture) {
+        let path = self.dir.join(format!(
+            "frame-{:05}.{}",
+            self.count,
+            self.format.extension()
+        ));
+        if let Err(e) = picture.write(&path, self.format) {
+            warn!("{}: {e}", path.display());
+        }
+
+        self.count += 1;
+    }
+}
+
+impl Drop for Frames {
+    fn drop(&mut self) {
+        info!("rendered {} frames to {}", self.count, self.dir.display());
+    }
+}
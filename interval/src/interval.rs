@@ -0,0 +1,92 @@
+use std::{
+    fmt::Display,
+    ops::{Add, Range, Sub},
+};
+
+/// A value intervals can range over: any integer type.
+pub trait Point: Copy + Ord + Default + Add<Output = Self> + Sub<Output = Self> {}
+
+impl<T> Point for T where T: Copy + Ord + Default + Add<Output = T> + Sub<Output = T> {}
+
+/// The half-open interval `[start, end)`.
+///
+/// An interval whose end is before its start is empty, and is stored as `[start, start)`, so
+/// `len` never underflows.
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Copy)]
+pub struct Interval<T> {
+    start: T,
+    end: T,
+}
+
+impl<T: Point> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        Self {
+            start,
+            end: end.max(start),
+        }
+    }
+
+    /// The `len` values from `start`.
+    pub fn with_len(start: T, len: T) -> Self {
+        Self::new(start, start + len)
+    }
+
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    /// The first value past the interval.
+    pub fn end(&self) -> T {
+        self.end
+    }
+
+    /// How many values are in the interval.
+    pub fn len(&self) -> T {
+        self.end - self.start
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// The values in both intervals, or `None` if there aren't any.
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        Some(Self::new(
+            self.start.max(other.start),
+            self.end.min(other.end),
+        ))
+        .filter(|i| !i.is_empty())
+    }
+
+    /// The values below `at`, and the values from `at` on. Either is `None` if it would be empty.
+    pub fn split_at(&self, at: T) -> (Option<Self>, Option<Self>) {
+        let below = Self::new(self.start, self.end.min(at));
+        let above = Self::new(self.start.max(at), self.end);
+
+        (
+            Some(below).filter(|i| !i.is_empty()),
+            Some(above).filter(|i| !i.is_empty()),
+        )
+    }
+
+    /// The interval moved by `offset`.
+    pub fn shift(&self, offset: T) -> Self {
+        Self::new(self.start + offset, self.end + offset)
+    }
+}
+
+impl<T: Point> From<Range<T>> for Interval<T> {
+    fn from(range: Range<T>) -> Self {
+        Self::new(range.start, range.end)
+    }
+}
+
+impl<T: Display> Display for Interval<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}, {})", self.start, self.end)
+    }
+}
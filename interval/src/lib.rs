@@ -0,0 +1,5 @@
+mod interval;
+mod set;
+
+pub use interval::{Interval, Point};
+pub use set::IntervalSet;
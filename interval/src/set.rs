@@ -0,0 +1,262 @@
+use std::fmt::Display;
+
+use crate::{Interval, Point};
+
+/// A set of values, stored as the fewest intervals that cover them.
+///
+/// The intervals are kept sorted, and never empty, overlapping or touching, so two sets with the
+/// same values always compare equal.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Default)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Point> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self {
+            intervals: Vec::new(),
+        }
+    }
+
+    // Sort and merge `intervals` into a valid set.
+    fn normalize(mut intervals: Vec<Interval<T>>) -> Self {
+        intervals.retain(|i| !i.is_empty());
+        intervals.sort_unstable();
+
+        let mut merged: Vec<Interval<T>> = Vec::with_capacity(intervals.len());
+        for i in intervals {
+            match merged.last_mut() {
+                Some(last) if i.start() <= last.end() => {
+                    *last = Interval::new(last.start(), last.end().max(i.end()));
+                }
+                _ => merged.push(i),
+            }
+        }
+
+        Self { intervals: merged }
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        let mut intervals = std::mem::take(&mut self.intervals);
+        intervals.push(interval);
+        *self = Self::normalize(intervals);
+    }
+
+    /// The intervals in the set, in order.
+    pub fn iter(&self) -> impl Iterator<Item = &Interval<T>> {
+        self.intervals.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// How many values are in the set.
+    pub fn len(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::default(), |sum, i| sum + i.len())
+    }
+
+    /// The smallest value in the set.
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|i| i.start())
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.intervals.iter().any(|i| i.contains(value))
+    }
+
+    /// The values in either set.
+    pub fn union(&self, other: &Self) -> Self {
+        Self::normalize(self.iter().chain(other.iter()).copied().collect())
+    }
+
+    /// The values in both sets.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let (a, b) = (&self.intervals, &other.intervals);
+        let mut intervals = Vec::new();
+
+        let (mut i, mut j) = (0, 0);
+        while i < a.len() && j < b.len() {
+            intervals.extend(a[i].intersect(&b[j]));
+
+            // Whichever ends first can't overlap anything else in the other set.
+            if a[i].end() < b[j].end() {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        Self { intervals }
+    }
+
+    /// The values in this set but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let b = &other.intervals;
+        let mut intervals = Vec::new();
+
+        let mut first = 0;
+        for i in &self.intervals {
+            let mut start = i.start();
+
+            // Skip what's entirely below this interval, and so below every later one too.
+            while first < b.len() && b[first].end() <= start {
+                first += 1;
+            }
+
+            for cut in b[first..].iter().take_while(|c| c.start() < i.end()) {
+                if cut.start() > start {
+                    intervals.push(Interval::new(start, cut.start()));
+                }
+                start = start.max(cut.end());
+            }
+
+            if start < i.end() {
+                intervals.push(Interval::new(start, i.end()));
+            }
+        }
+
+        Self { intervals }
+    }
+
+    /// The values below `at`, and the values from `at` on.
+    pub fn split_at(&self, at: T) -> (Self, Self) {
+        let (mut below, mut above) = (Vec::new(), Vec::new());
+
+        for i in &self.intervals {
+            let (b, a) = i.split_at(at);
+            below.extend(b);
+            above.extend(a);
+        }
+
+        (Self { intervals: below }, Self { intervals: above })
+    }
+
+    /// Every value moved by `offset`.
+    pub fn shift(&self, offset: T) -> Self {
+        Self {
+            intervals: self.intervals.iter().map(|i| i.shift(offset)).collect(),
+        }
+    }
+}
+
+impl<T: Point> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        Self::normalize(vec![interval])
+    }
+}
+
+impl<T: Point> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        Self::normalize(iter.into_iter().collect())
+    }
+}
+
+impl<T: Display> Display for IntervalSet<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{")?;
+        for (idx, i) in self.intervals.iter().enumerate() {
+            if idx > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{i}")?;
+        }
+        write!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IntervalSet;
+    use crate::Interval;
+
+    fn set(ranges: &[(i64, i64)]) -> IntervalSet<i64> {
+        ranges.iter().map(|&(s, e)| Interval::new(s, e)).collect()
+    }
+
+    fn ranges(set: &IntervalSet<i64>) -> Vec<(i64, i64)> {
+        set.iter().map(|i| (i.start(), i.end())).collect()
+    }
+
+    #[test]
+    fn normalize() {
+        // Touching intervals merge, as do overlapping and nested ones, and empty ones vanish.
+        assert_eq!(ranges(&set(&[(2, 4), (0, 2)])), [(0, 4)]);
+        assert_eq!(ranges(&set(&[(0, 5), (3, 8), (4, 6)])), [(0, 8)]);
+        assert_eq!(ranges(&set(&[(0, 1), (2, 3)])), [(0, 1), (2, 3)]);
+        assert_eq!(ranges(&set(&[(3, 3), (5, 1)])), []);
+        assert!(set(&[(3, 3)]).is_empty());
+
+        let mut s = set(&[(0, 2), (4, 6)]);
+        s.insert(Interval::new(2, 4));
+        assert_eq!(ranges(&s), [(0, 6)]);
+    }
+
+    #[test]
+    fn intersection() {
+        let a = set(&[(0, 5), (10, 15)]);
+
+        assert_eq!(
+            ranges(&a.intersection(&set(&[(3, 12)]))),
+            [(3, 5), (10, 12)]
+        );
+        // Touching isn't overlapping.
+        assert_eq!(ranges(&a.intersection(&set(&[(5, 10)]))), []);
+        assert_eq!(ranges(&a.intersection(&IntervalSet::new())), []);
+        assert_eq!(a.intersection(&a), a);
+    }
+
+    #[test]
+    fn difference() {
+        let a = set(&[(0, 10)]);
+
+        assert_eq!(ranges(&a.difference(&set(&[(3, 5)]))), [(0, 3), (5, 10)]);
+        // Cuts at either edge, or beyond them.
+        assert_eq!(ranges(&a.difference(&set(&[(0, 3)]))), [(3, 10)]);
+        assert_eq!(ranges(&a.difference(&set(&[(7, 10)]))), [(0, 7)]);
+        assert_eq!(ranges(&a.difference(&set(&[(-5, 2), (8, 20)]))), [(2, 8)]);
+        // Cuts that only touch take nothing.
+        assert_eq!(ranges(&a.difference(&set(&[(-5, 0), (10, 12)]))), [(0, 10)]);
+        // Nothing left.
+        assert_eq!(ranges(&a.difference(&a)), []);
+        assert_eq!(ranges(&a.difference(&set(&[(-1, 11)]))), []);
+        assert_eq!(ranges(&IntervalSet::new().difference(&a)), []);
+
+        // One cut spanning several intervals.
+        let b = set(&[(0, 2), (4, 6), (8, 10)]);
+        assert_eq!(ranges(&b.difference(&set(&[(1, 9)]))), [(0, 1), (9, 10)]);
+    }
+
+    #[test]
+    fn split_at() {
+        let a = set(&[(0, 5), (10, 15)]);
+
+        let (below, above) = a.split_at(12);
+        assert_eq!(
+            (ranges(&below), ranges(&above)),
+            (vec![(0, 5), (10, 12)], vec![(12, 15)])
+        );
+
+        // Splitting at an edge or in a gap splits between intervals.
+        let (below, above) = a.split_at(5);
+        assert_eq!(
+            (ranges(&below), ranges(&above)),
+            (vec![(0, 5)], vec![(10, 15)])
+        );
+        let (below, above) = a.split_at(7);
+        assert_eq!(
+            (ranges(&below), ranges(&above)),
+            (vec![(0, 5)], vec![(10, 15)])
+        );
+
+        // Splitting outside leaves one side empty.
+        let (below, above) = a.split_at(0);
+        assert!(below.is_empty());
+        assert_eq!(above, a);
+        let (below, above) = a.split_at(20);
+        assert_eq!(below, a);
+        assert!(above.is_empty());
+    }
+}